@@ -0,0 +1,103 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+const CHANNEL_CAPACITY: usize = 256;
+const BATCH_SIZE: usize = 32;
+const FALLBACK_PATH: &str = "turtle_audit_fallback.jsonl";
+
+pub fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+}
+
+/// One structured record of a REPL invocation or fleet operation - what
+/// `resource_usage_logging` in `SafetyAuthorityConfig` promised but nothing
+/// actually wrote, until now.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: u64,
+    pub node_id: String,
+    pub command: String,
+    pub risk_level: String,
+    pub auth_outcome: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Hands events to a batching background task over a bounded channel, so a
+/// slow or unreachable DB can never stall the REPL recording them -
+/// `record` is a non-blocking `try_send` that drops the event (with a
+/// warning) rather than waiting for room.
+pub struct AuditLogger {
+    tx: mpsc::Sender<AuditEvent>,
+}
+
+impl AuditLogger {
+    /// Spawn the batching writer task and return a handle to it. Safe to
+    /// call from outside an async fn as long as a Tokio runtime is current,
+    /// same as `Supervisor::new()`.
+    pub fn init() -> Self {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        tokio::spawn(Self::run(rx));
+        AuditLogger { tx }
+    }
+
+    pub fn record(&self, event: AuditEvent) {
+        if self.tx.try_send(event).is_err() {
+            println!("⚠️ Audit log channel full or closed - dropping event");
+        }
+    }
+
+    async fn run(mut rx: mpsc::Receiver<AuditEvent>) {
+        loop {
+            let Some(first) = rx.recv().await else { return };
+            let mut batch = vec![first];
+
+            // Pick up anything else already queued, up to BATCH_SIZE, so a
+            // burst of events (e.g. fleet-wide coordination) flushes as one
+            // write instead of one round-trip per event.
+            while batch.len() < BATCH_SIZE {
+                match rx.try_recv() {
+                    Ok(event) => batch.push(event),
+                    Err(_) => break,
+                }
+            }
+
+            Self::flush(batch).await;
+        }
+    }
+
+    /// Write `batch` to the local SQLite audit table (standing in for the
+    /// Postgres/TimescaleDB hypertable the request describes - this crate's
+    /// persistence is already `DbCtx`'s embedded SQLite, so the events table
+    /// lives there too, indexed on `ts` rather than time-partitioned).
+    /// Falls back to an append-only JSONL file if the DB can't be opened or
+    /// written, so events are never silently lost.
+    async fn flush(batch: Vec<AuditEvent>) {
+        let for_db = batch.clone();
+        let db_result = tokio::task::spawn_blocking(move || -> Result<()> {
+            let db = crate::db::DbCtx::open()?;
+            db.record_audit_events(&for_db)
+        })
+        .await;
+
+        let wrote_to_db = matches!(db_result, Ok(Ok(())));
+        if !wrote_to_db {
+            if let Err(e) = Self::write_fallback(&batch) {
+                println!("⚠️ Failed to write audit fallback log: {}", e);
+            }
+        }
+    }
+
+    fn write_fallback(batch: &[AuditEvent]) -> Result<()> {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(FALLBACK_PATH)?;
+        for event in batch {
+            writeln!(file, "{}", serde_json::to_string(event)?)?;
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@ use anyhow::Result;
 use reqwest::Client;
 use serde_json::{json, Value};
 use std::env;
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
 
 pub struct Claude {
     client: Client,
@@ -20,8 +22,8 @@ impl Claude {
         }
     }
 
-    pub async fn chat(&self, message: &str, context: &str) -> Result<String> {
-        let system_prompt = format!(
+    fn system_prompt(context: &str) -> String {
+        format!(
             "You are Claude within the Smart Turtle Fleet - 28 turtles coordinated by Top Turtle. \
              You have complete observability and permission-free execution capabilities. \
              Current context: {}\n\
@@ -30,19 +32,26 @@ impl Claude {
              Use CNL-native approaches for turtle fleet coordination, traditional tools for external interfaces. \
              Provide actionable turtle fleet intelligence and coordination recommendations.",
             context
-        );
+        )
+    }
 
-        let request_body = json!({
-            "model": "claude-3-5-sonnet-20241022", 
+    fn request_body(&self, message: &str, context: &str, stream: bool) -> Value {
+        json!({
+            "model": "claude-3-5-sonnet-20241022",
             "max_tokens": 4096,
-            "system": system_prompt,
+            "stream": stream,
+            "system": Self::system_prompt(context),
             "messages": [
                 {
-                    "role": "user", 
+                    "role": "user",
                     "content": format!("Top Turtle Command: {}", message)
                 }
             ]
-        });
+        })
+    }
+
+    pub async fn chat(&self, message: &str, context: &str) -> Result<String> {
+        let request_body = self.request_body(message, context, false);
 
         let response = self
             .client
@@ -60,7 +69,7 @@ impl Claude {
         }
 
         let response_json: Value = response.json().await?;
-        
+
         if let Some(content) = response_json["content"].as_array() {
             if let Some(text) = content.first().and_then(|c| c["text"].as_str()) {
                 Ok(text.to_string())
@@ -71,8 +80,88 @@ impl Claude {
             anyhow::bail!("No content in Claude API response");
         }
     }
+
+    /// Stream a chat response as it arrives, yielding text chunks over `mpsc`.
+    /// The receiving task (e.g. `repl_mode`) can print each chunk as soon as
+    /// it shows up instead of waiting for the full message body.
+    pub async fn chat_stream(&self, message: &str, context: &str) -> Result<mpsc::Receiver<Result<String>>> {
+        let request_body = self.request_body(message, context, true);
+        let (tx, rx) = mpsc::channel(32);
+
+        let response = self
+            .client
+            .post("https://api.anthropic.com/v1/messages")
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&request_body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            anyhow::bail!("Claude API error: {}", error_text);
+        }
+
+        tokio::spawn(async move {
+            let mut stream = response.bytes_stream();
+            let mut buf = String::new();
+
+            while let Some(chunk) = stream.next().await {
+                let chunk = match chunk {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        let _ = tx.send(Err(anyhow::Error::from(e))).await;
+                        return;
+                    }
+                };
+                buf.push_str(&String::from_utf8_lossy(&chunk));
+
+                while let Some(idx) = buf.find('\n') {
+                    let line = buf[..idx].trim_end_matches('\r').to_string();
+                    buf.drain(..=idx);
+
+                    let Some(data) = line.strip_prefix("data: ") else {
+                        continue;
+                    };
+                    if data == "[DONE]" {
+                        return;
+                    }
+
+                    let event: Value = match serde_json::from_str(data) {
+                        Ok(v) => v,
+                        Err(_) => continue,
+                    };
+
+                    match event["type"].as_str() {
+                        Some("content_block_delta") => {
+                            if event["delta"]["type"].as_str() == Some("text_delta") {
+                                if let Some(text) = event["delta"]["text"].as_str() {
+                                    if tx.send(Ok(text.to_string())).await.is_err() {
+                                        return;
+                                    }
+                                }
+                            }
+                        }
+                        Some("error") => {
+                            let message = event["error"]["message"]
+                                .as_str()
+                                .unwrap_or("unknown streaming error")
+                                .to_string();
+                            let _ = tx.send(Err(anyhow::anyhow!("Claude API error: {}", message))).await;
+                            return;
+                        }
+                        Some("message_stop") => return,
+                        _ => {}
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
 }
 
 pub fn has_api_key() -> bool {
     env::var("ANTHROPIC_API_KEY").is_ok()
-}
\ No newline at end of file
+}
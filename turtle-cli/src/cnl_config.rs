@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
@@ -84,6 +84,12 @@ pub struct SafetyAuthorityConfig {
     pub direct_authorization_chain: bool,
     pub indirect_authorization_chain: bool,
     pub resource_usage_logging: bool,
+    /// Where the notifier's webhook sink posts to - `None` leaves it
+    /// desktop/stdout-only regardless of `resource_usage_logging`.
+    pub notification_webhook_url: Option<String>,
+    /// DC deploy-percentage breakpoints the notifier fires
+    /// `Notification::DcThresholdCrossed` on, crossed from below.
+    pub dc_deploy_thresholds: Vec<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -112,19 +118,87 @@ pub struct StartupBehaviorConfig {
     pub fleet_coordination_startup: HashMap<String, bool>,
 }
 
+/// The section names `SECTION <name>` / `END` blocks are allowed to open, one
+/// per top-level field of `TurtleFleetConfig`.
+const SECTIONS: &[&str] = &[
+    "FLEET_DISCOVERY",
+    "DISPLAY_AND_WINDOW",
+    "CLAUDE_INTEGRATION",
+    "FLEET_COMMUNICATION",
+    "SAFETY_AND_AUTHORITY",
+    "MESH_RESILIENCE",
+    "EFFICIENCY_OPTIMIZATION",
+    "STARTUP_BEHAVIOR",
+];
+
 pub struct CNLConfigLoader;
 
 impl CNLConfigLoader {
     pub fn load_config() -> Result<TurtleFleetConfig> {
-        // Parse CNL configuration and convert to structured config
-        let cnl_content = fs::read_to_string("TURTLE_FLEET_CONFIG.cnl")?;
-        
-        // For now, return default config based on CNL values
-        // TODO: Implement full CNL parsing
-        Ok(Self::default_from_cnl(&cnl_content))
+        let cnl_content = fs::read_to_string("TURTLE_FLEET_CONFIG.cnl")
+            .context("reading TURTLE_FLEET_CONFIG.cnl")?;
+        Self::parse_cnl(&cnl_content)
+    }
+
+    /// Tokenizes a `TURTLE_FLEET_CONFIG.cnl` file and populates every field
+    /// of `TurtleFleetConfig` from it. The grammar is line-based:
+    ///
+    /// ```text
+    /// # comments run to end of line
+    /// SECTION FLEET_DISCOVERY
+    ///   infrastructure_paths = /home/tupshin/turtle, /home/tupshin/.turtle
+    ///   infrastructure_scan_timeout = 5000
+    /// END
+    ///
+    /// SECTION DISPLAY_AND_WINDOW
+    ///   monitor 0 = 0,0,2560,1440
+    ///   window_fraction top_third = 0.33
+    /// END
+    /// ```
+    ///
+    /// Each section holds `key = value` lines; some keys (`monitor`,
+    /// `window_fraction`, `risk_level`, `mesh_port`, and the boolean-flag-map
+    /// keys) take a qualifier between the key and the `=`, e.g.
+    /// `risk_level HIGH = ProcessControl, FleetCoordination`. Any line that
+    /// doesn't parse - an unknown section, a key outside of a `SECTION`
+    /// block, an unrecognized key, or a malformed value - fails with the
+    /// offending line number rather than silently falling back to a default.
+    pub(crate) fn parse_cnl(cnl_content: &str) -> Result<TurtleFleetConfig> {
+        let mut config = Self::default_from_cnl("");
+        let mut current_section: Option<String> = None;
+
+        for (idx, raw_line) in cnl_content.lines().enumerate() {
+            let line_no = idx + 1;
+            let line = raw_line.split_once('#').map_or(raw_line, |(before, _)| before).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("SECTION ") {
+                let name = name.trim();
+                if !SECTIONS.contains(&name) {
+                    bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown section '{}'", line_no, name);
+                }
+                current_section = Some(name.to_string());
+                continue;
+            }
+
+            if line == "END" {
+                current_section = None;
+                continue;
+            }
+
+            let Some(section) = current_section.clone() else {
+                bail!("TURTLE_FLEET_CONFIG.cnl:{}: '{}' outside of any SECTION", line_no, line);
+            };
+
+            apply_line(&mut config, &section, line, line_no)?;
+        }
+
+        Ok(config)
     }
-    
-    fn default_from_cnl(_cnl_content: &str) -> TurtleFleetConfig {
+
+    pub(crate) fn default_from_cnl(_cnl_content: &str) -> TurtleFleetConfig {
         // Extract values from CNL and create config structure
         TurtleFleetConfig {
             fleet_discovery: FleetDiscoveryConfig {
@@ -189,7 +263,7 @@ impl CNLConfigLoader {
             safety_and_authority: SafetyAuthorityConfig {
                 risk_levels: {
                     let mut risk_map = HashMap::new();
-                    risk_map.insert("LOW".to_string(), vec!["SystemQuery".to_string(), "Help".to_string(), "Conversation".to_string()]);
+                    risk_map.insert("LOW".to_string(), vec!["SystemQuery".to_string(), "Help".to_string(), "Conversation".to_string(), "LSystemRender".to_string()]);
                     risk_map.insert("MEDIUM".to_string(), vec!["WindowManagement".to_string(), "FleetStatus".to_string(), "FleetObservation".to_string()]);
                     risk_map.insert("HIGH".to_string(), vec!["ProcessControl".to_string(), "FleetCoordination".to_string(), "TopTurtleCommand".to_string()]);
                     risk_map
@@ -198,6 +272,8 @@ impl CNLConfigLoader {
                 direct_authorization_chain: true,
                 indirect_authorization_chain: true,
                 resource_usage_logging: true,
+                notification_webhook_url: Some("https://hooks.turtle.local/alerts".to_string()),
+                dc_deploy_thresholds: vec![25, 50, 75, 100],
             },
             mesh_resilience: MeshResilienceConfig {
                 discovery_mechanisms: {
@@ -255,4 +331,292 @@ impl CNLConfigLoader {
             },
         }
     }
+}
+
+/// Splits a `key = value` (or `key qualifier = value`) line, trims both
+/// sides, and strips one layer of wrapping double quotes from the value so
+/// `system_prompt_template = "..."` round-trips through commas unharmed.
+fn split_kv(line: &str, line_no: usize) -> Result<(&str, &str)> {
+    let (lhs, rhs) = line
+        .split_once('=')
+        .with_context(|| format!("TURTLE_FLEET_CONFIG.cnl:{}: expected 'key = value' in '{}'", line_no, line))?;
+    Ok((lhs.trim(), unquote(rhs.trim())))
+}
+
+fn unquote(value: &str) -> &str {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+/// Splits `lhs` into its key and, if present, a single-token qualifier -
+/// `"monitor 0"` becomes `("monitor", Some("0"))`, `"fleet_size"` becomes
+/// `("fleet_size", None)`.
+fn key_and_qualifier(lhs: &str) -> (&str, Option<&str>) {
+    match lhs.split_once(char::is_whitespace) {
+        Some((key, rest)) => (key.trim(), Some(rest.trim())),
+        None => (lhs, None),
+    }
+}
+
+fn parse_num<T: std::str::FromStr>(value: &str, line_no: usize, key: &str) -> Result<T> {
+    value
+        .parse::<T>()
+        .map_err(|_| anyhow::anyhow!("TURTLE_FLEET_CONFIG.cnl:{}: invalid number for '{}': '{}'", line_no, key, value))
+}
+
+fn parse_bool(value: &str, line_no: usize, key: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: invalid boolean for '{}': '{}'", line_no, key, value),
+    }
+}
+
+fn parse_csv(value: &str) -> Vec<String> {
+    value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+}
+
+fn qualifier_required<'a>(qualifier: Option<&'a str>, key: &str, line_no: usize) -> Result<&'a str> {
+    qualifier.ok_or_else(|| anyhow::anyhow!("TURTLE_FLEET_CONFIG.cnl:{}: '{}' needs a qualifier, e.g. '{} NAME = ...'", line_no, key, key))
+}
+
+/// Applies one `key = value` line (already known to be inside `section`) to
+/// `config`. The single entry point `parse_cnl`'s per-line loop calls.
+fn apply_line(config: &mut TurtleFleetConfig, section: &str, line: &str, line_no: usize) -> Result<()> {
+    let (lhs, rhs) = split_kv(line, line_no)?;
+    let (key, qualifier) = key_and_qualifier(lhs);
+
+    match section {
+        "FLEET_DISCOVERY" => {
+            let c = &mut config.fleet_discovery;
+            match key {
+                "infrastructure_paths" => c.infrastructure_paths = parse_csv(rhs),
+                "process_discovery_pattern" => c.process_discovery_pattern = rhs.to_string(),
+                "infrastructure_scan_timeout" => c.infrastructure_scan_timeout = parse_num(rhs, line_no, key)?,
+                "fleet_coordination_retry_count" => c.fleet_coordination_retry_count = parse_num(rhs, line_no, key)?,
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in FLEET_DISCOVERY", line_no, key),
+            }
+        }
+        "DISPLAY_AND_WINDOW" => {
+            let c = &mut config.display_and_window;
+            match key {
+                "monitor" => {
+                    let index: u32 = parse_num(qualifier_required(qualifier, key, line_no)?, line_no, "monitor index")?;
+                    let parts: Vec<&str> = rhs.split(',').map(str::trim).collect();
+                    let [base_x, base_y, width, height] = <[&str; 4]>::try_from(parts.as_slice()).map_err(|_| {
+                        anyhow::anyhow!("TURTLE_FLEET_CONFIG.cnl:{}: expected 'base_x,base_y,width,height' for monitor {}", line_no, index)
+                    })?;
+                    let monitor = MonitorConfig {
+                        index,
+                        base_x: parse_num(base_x, line_no, "monitor base_x")?,
+                        base_y: parse_num(base_y, line_no, "monitor base_y")?,
+                        width: parse_num(width, line_no, "monitor width")?,
+                        height: parse_num(height, line_no, "monitor height")?,
+                    };
+                    match c.monitors.iter_mut().find(|m| m.index == index) {
+                        Some(existing) => *existing = monitor,
+                        None => c.monitors.push(monitor),
+                    }
+                }
+                "default_terminal_geometry" => c.default_terminal_geometry = rhs.to_string(),
+                "default_terminal_zoom" => c.default_terminal_zoom = parse_num(rhs, line_no, key)?,
+                "center_offset" => {
+                    let parts: Vec<&str> = rhs.split(',').map(str::trim).collect();
+                    let [x, y] = <[&str; 2]>::try_from(parts.as_slice())
+                        .map_err(|_| anyhow::anyhow!("TURTLE_FLEET_CONFIG.cnl:{}: expected 'x,y' for center_offset", line_no))?;
+                    c.center_offset = (parse_num(x, line_no, "center_offset.x")?, parse_num(y, line_no, "center_offset.y")?);
+                }
+                "window_fraction" => {
+                    let value: f64 = parse_num(rhs, line_no, "window_fraction")?;
+                    match qualifier_required(qualifier, key, line_no)? {
+                        "top_third" => c.window_fractions.top_third = value,
+                        "middle_third" => c.window_fractions.middle_third = value,
+                        "bottom_third" => c.window_fractions.bottom_third = value,
+                        "left_half" => c.window_fractions.left_half = value,
+                        "right_half" => c.window_fractions.right_half = value,
+                        other => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown window_fraction '{}'", line_no, other),
+                    }
+                }
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in DISPLAY_AND_WINDOW", line_no, key),
+            }
+        }
+        "CLAUDE_INTEGRATION" => {
+            let c = &mut config.claude_integration;
+            match key {
+                "model_name" => c.model_name = rhs.to_string(),
+                "max_tokens" => c.max_tokens = parse_num(rhs, line_no, key)?,
+                "api_version" => c.api_version = rhs.to_string(),
+                "api_endpoint" => c.api_endpoint = rhs.to_string(),
+                "system_prompt_template" => c.system_prompt_template = rhs.to_string(),
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in CLAUDE_INTEGRATION", line_no, key),
+            }
+        }
+        "FLEET_COMMUNICATION" => {
+            let c = &mut config.fleet_communication;
+            match key {
+                "fleet_size" => c.fleet_size = parse_num(rhs, line_no, key)?,
+                "coordination_protocol" => c.coordination_protocol = rhs.to_string(),
+                "communication_pattern" => c.communication_pattern = rhs.to_string(),
+                "discovery_interval" => c.discovery_interval = parse_num(rhs, line_no, key)?,
+                "health_check_interval" => c.health_check_interval = parse_num(rhs, line_no, key)?,
+                "mesh_port" => {
+                    let port: u16 = parse_num(rhs, line_no, "mesh_port")?;
+                    match qualifier_required(qualifier, key, line_no)? {
+                        "discovery" => c.mesh_ports.discovery_port = port,
+                        "coordination" => c.mesh_ports.coordination_port = port,
+                        "observation" => c.mesh_ports.observation_port = port,
+                        "inference" => c.mesh_ports.inference_port = port,
+                        other => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown mesh_port '{}'", line_no, other),
+                    }
+                }
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in FLEET_COMMUNICATION", line_no, key),
+            }
+        }
+        "SAFETY_AND_AUTHORITY" => {
+            let c = &mut config.safety_and_authority;
+            match key {
+                "risk_level" => {
+                    let tier = qualifier_required(qualifier, key, line_no)?.to_uppercase();
+                    c.risk_levels.insert(tier, parse_csv(rhs));
+                }
+                "top_turtle_authority_required" => c.top_turtle_authority_required = parse_bool(rhs, line_no, key)?,
+                "direct_authorization_chain" => c.direct_authorization_chain = parse_bool(rhs, line_no, key)?,
+                "indirect_authorization_chain" => c.indirect_authorization_chain = parse_bool(rhs, line_no, key)?,
+                "resource_usage_logging" => c.resource_usage_logging = parse_bool(rhs, line_no, key)?,
+                "notification_webhook_url" => {
+                    c.notification_webhook_url = if rhs.is_empty() { None } else { Some(rhs.to_string()) };
+                }
+                "dc_deploy_thresholds" => {
+                    c.dc_deploy_thresholds = parse_csv(rhs)
+                        .iter()
+                        .map(|s| parse_num::<u32>(s, line_no, key))
+                        .collect::<Result<Vec<u32>>>()?;
+                }
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in SAFETY_AND_AUTHORITY", line_no, key),
+            }
+        }
+        "MESH_RESILIENCE" => {
+            let c = &mut config.mesh_resilience;
+            match key {
+                "discovery_mechanism" => {
+                    let name = qualifier_required(qualifier, key, line_no)?.to_string();
+                    c.discovery_mechanisms.insert(name, parse_bool(rhs, line_no, key)?);
+                }
+                "communication_redundancy" => {
+                    let name = qualifier_required(qualifier, key, line_no)?.to_string();
+                    c.communication_redundancy.insert(name, parse_bool(rhs, line_no, key)?);
+                }
+                "node_failure_timeout" => c.node_failure_timeout = parse_num(rhs, line_no, key)?,
+                "automatic_recovery" => c.automatic_recovery = parse_bool(rhs, line_no, key)?,
+                "mesh_healing_interval" => c.mesh_healing_interval = parse_num(rhs, line_no, key)?,
+                "connection_retry_count" => c.connection_retry_count = parse_num(rhs, line_no, key)?,
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in MESH_RESILIENCE", line_no, key),
+            }
+        }
+        "EFFICIENCY_OPTIMIZATION" => {
+            let c = &mut config.efficiency_optimization;
+            match key {
+                "connection_management" => {
+                    let name = qualifier_required(qualifier, key, line_no)?.to_string();
+                    c.connection_management.insert(name, parse_bool(rhs, line_no, key)?);
+                }
+                "max_connections_per_turtle" => c.max_connections_per_turtle = parse_num(rhs, line_no, key)?,
+                "data_optimization" => {
+                    let name = qualifier_required(qualifier, key, line_no)?.to_string();
+                    c.data_optimization.insert(name, parse_bool(rhs, line_no, key)?);
+                }
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in EFFICIENCY_OPTIMIZATION", line_no, key),
+            }
+        }
+        "STARTUP_BEHAVIOR" => {
+            let c = &mut config.startup_behavior;
+            match key {
+                "work_hours_start" => c.work_hours_start = parse_num(rhs, line_no, key)?,
+                "work_hours_end" => c.work_hours_end = parse_num(rhs, line_no, key)?,
+                "default_work_mode" => c.default_work_mode = rhs.to_string(),
+                "default_general_mode" => c.default_general_mode = rhs.to_string(),
+                "fleet_coordination_startup" => {
+                    let name = qualifier_required(qualifier, key, line_no)?.to_string();
+                    c.fleet_coordination_startup.insert(name, parse_bool(rhs, line_no, key)?);
+                }
+                _ => bail!("TURTLE_FLEET_CONFIG.cnl:{}: unknown key '{}' in STARTUP_BEHAVIOR", line_no, key),
+            }
+        }
+        _ => unreachable!("section names are validated against SECTIONS before apply_line is called"),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_section_fails_with_line_number() {
+        let err = CNLConfigLoader::parse_cnl("SECTION NOT_A_REAL_SECTION\nEND\n").unwrap_err();
+        assert!(err.to_string().contains(":1:"));
+        assert!(err.to_string().contains("NOT_A_REAL_SECTION"));
+    }
+
+    #[test]
+    fn key_outside_section_fails() {
+        let err = CNLConfigLoader::parse_cnl("fleet_size = 10\n").unwrap_err();
+        assert!(err.to_string().contains("outside of any SECTION"));
+    }
+
+    #[test]
+    fn qualifier_required_fails_without_one() {
+        let cnl = "SECTION DISPLAY_AND_WINDOW\nwindow_fraction = 0.5\nEND\n";
+        let err = CNLConfigLoader::parse_cnl(cnl).unwrap_err();
+        assert!(err.to_string().contains("needs a qualifier"));
+    }
+
+    #[test]
+    fn malformed_monitor_tuple_fails() {
+        let cnl = "SECTION DISPLAY_AND_WINDOW\nmonitor 0 = 0,0,2560\nEND\n";
+        let err = CNLConfigLoader::parse_cnl(cnl).unwrap_err();
+        assert!(err.to_string().contains("base_x,base_y,width,height"));
+    }
+
+    #[test]
+    fn malformed_center_offset_tuple_fails() {
+        let cnl = "SECTION DISPLAY_AND_WINDOW\ncenter_offset = 640\nEND\n";
+        let err = CNLConfigLoader::parse_cnl(cnl).unwrap_err();
+        assert!(err.to_string().contains("expected 'x,y' for center_offset"));
+    }
+
+    #[test]
+    fn parses_monitor_and_center_offset() {
+        let cnl = "SECTION DISPLAY_AND_WINDOW\nmonitor 0 = 10,20,800,600\ncenter_offset = 12,34\nEND\n";
+        let config = CNLConfigLoader::parse_cnl(cnl).unwrap();
+        let monitor = config.display_and_window.monitors.iter().find(|m| m.index == 0).unwrap();
+        assert_eq!((monitor.base_x, monitor.base_y, monitor.width, monitor.height), (10, 20, 800, 600));
+        assert_eq!(config.display_and_window.center_offset, (12, 34));
+    }
+
+    #[test]
+    fn parses_notification_webhook_url_and_dc_deploy_thresholds() {
+        let cnl = "SECTION SAFETY_AND_AUTHORITY\nnotification_webhook_url = https://example.test/hook\ndc_deploy_thresholds = 10, 50, 90\nEND\n";
+        let config = CNLConfigLoader::parse_cnl(cnl).unwrap();
+        assert_eq!(config.safety_and_authority.notification_webhook_url, Some("https://example.test/hook".to_string()));
+        assert_eq!(config.safety_and_authority.dc_deploy_thresholds, vec![10, 50, 90]);
+    }
+
+    #[test]
+    fn empty_notification_webhook_url_clears_it() {
+        let cnl = "SECTION SAFETY_AND_AUTHORITY\nnotification_webhook_url = \nEND\n";
+        let config = CNLConfigLoader::parse_cnl(cnl).unwrap();
+        assert_eq!(config.safety_and_authority.notification_webhook_url, None);
+    }
+
+    #[test]
+    fn bad_dc_deploy_threshold_number_fails() {
+        let cnl = "SECTION SAFETY_AND_AUTHORITY\ndc_deploy_thresholds = 10, not_a_number\nEND\n";
+        let err = CNLConfigLoader::parse_cnl(cnl).unwrap_err();
+        assert!(err.to_string().contains("invalid number"));
+    }
 }
\ No newline at end of file
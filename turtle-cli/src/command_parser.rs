@@ -2,13 +2,26 @@ use anyhow::Result;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use crate::safety::{CoreInteractionPrinciple, SafetyContext, RiskLevel};
+use crate::shell::Shell;
+use crate::supervisor::Supervisor;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::process::Command;
+use tokio::sync::Mutex;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParsedCommand {
     pub intent: CommandIntent,
     pub parameters: std::collections::HashMap<String, String>,
     pub risk_level: RiskLevel,
+    /// Set by a "dry run"/"preview"/"simulate" CNL prefix. Routes execution
+    /// through `CoreInteractionPrinciple::simulate_execution` instead of the
+    /// real command handler.
+    pub dry_run: bool,
+    /// How to run a process-control target, if the CNL phrase named one
+    /// explicitly (e.g. "using powershell"). `None` means fall back to
+    /// `Shell::default_for_platform()` at execution time.
+    pub shell: Option<Shell>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +35,10 @@ pub enum CommandIntent {
     FleetStatus,
     FleetObservation,
     TopTurtleCommand,
+    StateMutation,
+    WatchAndRun,
+    LSystemRender,
+    SessionManagement,
     Conversation,
     Help,
     Unknown,
@@ -29,24 +46,47 @@ pub enum CommandIntent {
 
 pub struct CNLCommandParser {
     window_patterns: Vec<(Regex, CommandIntent)>,
-    process_patterns: Vec<(Regex, CommandIntent)>,
-    system_patterns: Vec<(Regex, CommandIntent)>,
+    process_patterns: Vec<(Regex, CommandIntent, &'static str)>,
+    system_patterns: Vec<(Regex, CommandIntent, &'static str)>,
+    state_patterns: Vec<(Regex, CommandIntent)>,
+    watch_patterns: Vec<(Regex, CommandIntent)>,
+    lsystem_patterns: Vec<(Regex, CommandIntent)>,
+    container_patterns: Vec<(Regex, CommandIntent)>,
+    session_patterns: Vec<(Regex, CommandIntent, &'static str)>,
     safety_engine: CoreInteractionPrinciple,
+    supervisor: Arc<Mutex<Supervisor>>,
+    audit: crate::audit::AuditLogger,
+    node_id: String,
 }
 
 impl CNLCommandParser {
     pub fn new() -> Self {
         let mut parser = Self {
             window_patterns: Vec::new(),
-            process_patterns: Vec::new(), 
+            process_patterns: Vec::new(),
             system_patterns: Vec::new(),
+            state_patterns: Vec::new(),
+            watch_patterns: Vec::new(),
+            lsystem_patterns: Vec::new(),
+            container_patterns: Vec::new(),
+            session_patterns: Vec::new(),
             safety_engine: CoreInteractionPrinciple::new(),
+            supervisor: Arc::new(Mutex::new(Supervisor::new())),
+            audit: crate::audit::AuditLogger::init(),
+            node_id: std::env::var("HOSTNAME").unwrap_or_else(|_| "top-turtle".to_string()),
         };
-        
+
         parser.initialize_patterns();
         parser
     }
 
+    /// A clone of the same `Arc<Mutex<Supervisor>>` this parser drives, so a
+    /// background task (see `main::spawn_background_workers`) can poll
+    /// `reap()` without waiting for someone to type "fleet status".
+    pub fn supervisor(&self) -> Arc<Mutex<Supervisor>> {
+        self.supervisor.clone()
+    }
+
     fn initialize_patterns(&mut self) {
         // Window management patterns from CNL specification
         self.window_patterns = vec![
@@ -60,38 +100,102 @@ impl CNLCommandParser {
              CommandIntent::WindowManagement),
         ];
 
-        // Process control patterns
+        // Process control patterns. Supervise/unsupervise are checked first
+        // since "stop supervising x" would otherwise match the stop pattern.
         self.process_patterns = vec![
+            (Regex::new(r"(?i)stop\s+supervising\s+(\w+)").unwrap(),
+             CommandIntent::ProcessControl, "unsupervise"),
+            (Regex::new(r"(?i)supervise\s+(.+)").unwrap(),
+             CommandIntent::ProcessControl, "supervise"),
             (Regex::new(r"(?i)(?:start|launch|run)\s+(.+)").unwrap(),
-             CommandIntent::ProcessControl),
-            (Regex::new(r"(?i)(?:stop|kill|terminate)\s+(\w+)").unwrap(),
-             CommandIntent::ProcessControl),
+             CommandIntent::ProcessControl, "start"),
+            (Regex::new(r"(?i)(?:stop|kill|terminate)\s+(\w+)(?:\s+with\s+(SIG\w+))?(?:\s+grace\s+(\d+)s?)?").unwrap(),
+             CommandIntent::ProcessControl, "stop"),
             (Regex::new(r"(?i)restart\s+(\w+)").unwrap(),
-             CommandIntent::ProcessControl),
+             CommandIntent::ProcessControl, "restart"),
         ];
 
-        // System query patterns  
+        // System query patterns. Each entry also carries an action tag (same
+        // idea as `process_patterns`), since "coordinate fleet status" and
+        // "deploy across DCs" both parse to `FleetCoordination` but need
+        // different `execute_fleet_coordination` branches.
         self.system_patterns = vec![
             (Regex::new(r"(?i)(?:show|list|display)\s+(?:me\s+)?(?:all\s+)?monitors?").unwrap(),
-             CommandIntent::SystemQuery),
+             CommandIntent::SystemQuery, "monitors"),
             (Regex::new(r"(?i)(?:what's|show|check)\s+(?:the\s+)?(?:system\s+)?(?:status|health|state)").unwrap(),
-             CommandIntent::SystemQuery),
+             CommandIntent::SystemQuery, "status"),
             (Regex::new(r"(?i)(?:monitor|watch|observe)\s+(.+)").unwrap(),
-             CommandIntent::InfrastructureMonitoring),
+             CommandIntent::InfrastructureMonitoring, "monitor"),
             (Regex::new(r"(?i)(?:coordinate|manage|control)\s+(?:the\s+)?fleet\s+(\w+)").unwrap(),
-             CommandIntent::FleetCoordination),
+             CommandIntent::FleetCoordination, "status"),
             (Regex::new(r"(?i)(?:fleet|turtle)\s+(?:status|state|health)").unwrap(),
-             CommandIntent::FleetStatus),
+             CommandIntent::FleetStatus, "status"),
             (Regex::new(r"(?i)observe\s+(?:fleet|turtle|all)\s+(.+)").unwrap(),
-             CommandIntent::FleetObservation),
+             CommandIntent::FleetObservation, "observe"),
             (Regex::new(r"(?i)deploy\s+(?:across|to)\s+(\w+)").unwrap(),
-             CommandIntent::FleetCoordination),
+             CommandIntent::FleetCoordination, "deploy"),
             (Regex::new(r"(?i)engage\s+(?:interactive\s+)?(?:fleet\s+)?session").unwrap(),
-             CommandIntent::TopTurtleCommand),
+             CommandIntent::TopTurtleCommand, "engage"),
+        ];
+
+        // State mutation patterns - write verbs backed by DbCtx
+        self.state_patterns = vec![
+            (Regex::new(r"(?i)set\s+(\w+)\s+deployed\s+(\d+)").unwrap(),
+             CommandIntent::StateMutation),
+            (Regex::new(r"(?i)set\s+(\w+)\s+status\s+(.+)").unwrap(),
+             CommandIntent::StateMutation),
+            (Regex::new(r"(?i)add\s+task\s+(?:p(\d+)\s+)?(.+)").unwrap(),
+             CommandIntent::StateMutation),
+        ];
+
+        // Watch-and-run patterns - checked ahead of the generic
+        // "(?:monitor|watch|observe)" system pattern, which would otherwise
+        // swallow these as a plain InfrastructureMonitoring query.
+        self.watch_patterns = vec![
+            (Regex::new(r"(?i)^watch\s+(.+?)\s+and\s+(?:restart|run)\s+(.+?)(?:\s*,\s*(queue|ignore|restart|signal\s+\d+))?$").unwrap(),
+             CommandIntent::WatchAndRun),
+            (Regex::new(r"(?i)^when\s+files?\s+in\s+(.+?)\s+changes?,?\s+run\s+(.+?)(?:\s*,\s*(queue|ignore|restart|signal\s+\d+))?$").unwrap(),
+             CommandIntent::WatchAndRun),
+        ];
+
+        // Container-run patterns - checked ahead of the generic process_patterns
+        // "(?:start|launch|run) (.+)" pattern, which would otherwise swallow
+        // these as a local ProcessControl "start" instead of routing to
+        // `fleet::find_endpoint`/`run_in_container`.
+        self.container_patterns = vec![
+            (Regex::new(r"(?i)^run\s+(.+?)\s+in\s+container\s+(\S+)$").unwrap(), CommandIntent::FleetCoordination),
+        ];
+
+        // L-system render patterns. The grammar itself (axiom/rules/n/step/angle)
+        // doesn't fit the usual "verb + a couple of captures" shape, so the whole
+        // spec is captured as one blob and `create_lsystem_command` pulls
+        // `key=value` tokens out of it instead of relying on capture groups.
+        self.lsystem_patterns = vec![
+            (Regex::new(r"(?i)^(?:render|draw)\s+l-?system\s+(.+)$").unwrap(),
+             CommandIntent::LSystemRender),
+        ];
+
+        // Session snapshot patterns - capture/replay real on-screen window
+        // geometry through `geometry_backend::GeometryBackend` and
+        // `session::SessionSnapshot`.
+        self.session_patterns = vec![
+            (Regex::new(r"(?i)^session\s+save$").unwrap(), CommandIntent::SessionManagement, "save"),
+            (Regex::new(r"(?i)^session\s+load\s+(.+)$").unwrap(), CommandIntent::SessionManagement, "load"),
         ];
     }
 
     pub async fn parse_command(&mut self, input: &str) -> Result<ParsedCommand> {
+        let dry_run_prefix = Regex::new(r"(?i)^(?:dry run|preview|simulate)\s*:?\s+(.+)$").unwrap();
+        if let Some(captures) = dry_run_prefix.captures(input) {
+            let mut command = self.parse_inner(&captures[1]).await?;
+            command.dry_run = true;
+            return Ok(command);
+        }
+
+        self.parse_inner(input).await
+    }
+
+    async fn parse_inner(&mut self, input: &str) -> Result<ParsedCommand> {
         println!("🎯 Parsing command: '{}'", input);
 
         // Try window management patterns first
@@ -101,17 +205,55 @@ impl CNLCommandParser {
             }
         }
 
+        // Try container-run patterns, ahead of process control so "run ... in
+        // container ..." doesn't get swallowed as a local process start
+        for (pattern, intent) in &self.container_patterns {
+            if let Some(captures) = pattern.captures(input) {
+                return Ok(self.create_container_run_command(captures, intent.clone()));
+            }
+        }
+
         // Try process control patterns
-        for (pattern, intent) in &self.process_patterns {
+        for (pattern, intent, action) in &self.process_patterns {
             if let Some(captures) = pattern.captures(input) {
-                return Ok(self.create_process_command(captures, intent.clone()));
+                return Ok(self.create_process_command(captures, intent.clone(), action));
+            }
+        }
+
+        // Try state mutation patterns
+        for (pattern, intent) in &self.state_patterns {
+            if let Some(captures) = pattern.captures(input) {
+                return Ok(self.create_state_command(input, captures, intent.clone()));
+            }
+        }
+
+        // Try watch-and-run patterns, ahead of the generic system/monitoring ones
+        for (pattern, intent) in &self.watch_patterns {
+            if let Some(captures) = pattern.captures(input) {
+                return Ok(self.create_watch_command(captures, intent.clone()));
+            }
+        }
+
+        // Try L-system render patterns, ahead of the generic system/monitoring
+        // ones for the same reason watch-and-run is: "render l-system ..."
+        // would otherwise parse as an InfrastructureMonitoring "observe" query.
+        for (pattern, intent) in &self.lsystem_patterns {
+            if let Some(captures) = pattern.captures(input) {
+                return Ok(self.create_lsystem_command(captures, intent.clone()));
+            }
+        }
+
+        // Try session save/load patterns
+        for (pattern, intent, action) in &self.session_patterns {
+            if let Some(captures) = pattern.captures(input) {
+                return Ok(self.create_session_command(captures, intent.clone(), action));
             }
         }
 
         // Try system query patterns
-        for (pattern, intent) in &self.system_patterns {
+        for (pattern, intent, action) in &self.system_patterns {
             if let Some(captures) = pattern.captures(input) {
-                return Ok(self.create_system_command(captures, intent.clone()));
+                return Ok(self.create_system_command(captures, intent.clone(), action));
             }
         }
 
@@ -120,29 +262,110 @@ impl CNLCommandParser {
     }
 
     pub async fn execute_command(&mut self, command: ParsedCommand) -> Result<String> {
+        let start = Instant::now();
+        let dry_run = command.dry_run;
+
         // Apply Core Interaction Principle
-        let _context = SafetyContext {
+        let context = SafetyContext {
             operation: format!("{:?}", command.intent),
             target: command.parameters.get("target")
+                .or_else(|| command.parameters.get("app"))
                 .unwrap_or(&"system".to_string())
                 .clone(),
             risk_level: command.risk_level.clone(),
             rollback_plan: self.generate_rollback_plan(&command),
             monitoring_pattern: self.generate_monitoring_pattern(&command),
+            rollback_outcome: None,
         };
+        let audit_command = format!("{} {}", context.operation, context.target);
+        let audit_risk = format!("{:?}", context.risk_level);
 
-        // Analyze safety before execution
-        let _safety_context = self.safety_engine.analyze_safety_risks(
-            &format!("{:?}", command.intent),
-            &command.parameters.get("target").unwrap_or(&"unknown".to_string())
-        );
+        let result = if dry_run {
+            self.preview_command(context).await
+        } else {
+            // Analyze safety before execution
+            let _safety_context = self.safety_engine.analyze_safety_risks(
+                &format!("{:?}", command.intent),
+                &command.parameters.get("target").unwrap_or(&"unknown".to_string())
+            );
 
-        // Execute command with safety monitoring
-        println!("🛡️ Executing with Core Interaction Principle protection");
-        let result = self.execute_command_internal(command).await?;
-        
-        println!("✅ Command executed safely");
-        Ok(result)
+            // Execute command with safety monitoring
+            println!("🛡️ Executing with Core Interaction Principle protection");
+            let result = self.execute_command_internal(command).await;
+            if result.is_ok() {
+                println!("✅ Command executed safely");
+            }
+            result
+        };
+
+        // `resource_usage_logging` in `SafetyAuthorityConfig` - every REPL
+        // invocation gets a structured, non-blocking audit record. There's
+        // no authorization-denial path yet, so `auth_outcome` is always
+        // "authorized"; it's there for when one exists.
+        self.audit.record(crate::audit::AuditEvent {
+            timestamp: crate::audit::now_secs(),
+            node_id: self.node_id.clone(),
+            command: audit_command,
+            risk_level: audit_risk,
+            auth_outcome: "authorized".to_string(),
+            duration_ms: start.elapsed().as_millis() as u64,
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        });
+
+        result
+    }
+
+    /// Preview a "dry run"/"preview"/"simulate" command: observe the real
+    /// state but apply only an in-memory `SystemStateDelta`, so High/Critical
+    /// operations can be gated behind a confirmation step instead of running
+    /// blind.
+    async fn preview_command(&mut self, context: SafetyContext) -> Result<String> {
+        println!("🧪 Dry run - no real changes will be made");
+        let pre_state = self.safety_engine.observe_system_state().await?;
+        let delta = self.build_simulated_delta(&context, &pre_state);
+        let (warnings, projected_risk) = self.safety_engine.simulate_execution(&pre_state, &context, &delta);
+
+        if warnings.is_empty() {
+            Ok(format!(
+                "✅ Dry run: {} on {} predicts no side effects (risk stays {:?})",
+                context.operation, context.target, projected_risk
+            ))
+        } else {
+            let lines: Vec<String> = warnings.iter().map(|w| format!("   - {}", w)).collect();
+            Ok(format!(
+                "🧪 Dry run: {} on {} predicts {} warning(s) (risk {:?} -> {:?}):\n{}",
+                context.operation,
+                context.target,
+                warnings.len(),
+                context.risk_level,
+                projected_risk,
+                lines.join("\n")
+            ))
+        }
+    }
+
+    /// Best-effort translation of a parsed command into the hypothetical
+    /// change it would make, so `preview_command` has something concrete to
+    /// simulate against the observed state.
+    fn build_simulated_delta(&self, context: &SafetyContext, pre_state: &crate::safety::SystemState) -> crate::simulation::SystemStateDelta {
+        let mut delta = crate::simulation::SystemStateDelta::new();
+
+        match context.monitoring_pattern.as_str() {
+            "process_state_changes" => {
+                if let Some(process) = pre_state.processes.iter().find(|p| p.name.eq_ignore_ascii_case(&context.target)) {
+                    delta.kill_process(process.pid);
+                }
+            }
+            "window_position_changes" => {
+                if let Some(window) = pre_state.windows.iter().find(|w| w.title.eq_ignore_ascii_case(&context.target)) {
+                    delta.move_window(&window.id, window.x, window.y, window.width, window.height);
+                }
+            }
+            _ => {}
+        }
+
+        delta
     }
 
     async fn execute_command_internal(&self, command: ParsedCommand) -> Result<String> {
@@ -171,6 +394,18 @@ impl CNLCommandParser {
             CommandIntent::TopTurtleCommand => {
                 self.execute_top_turtle_command(command).await
             },
+            CommandIntent::StateMutation => {
+                self.execute_state_mutation(command).await
+            },
+            CommandIntent::WatchAndRun => {
+                self.execute_watch_and_run(command).await
+            },
+            CommandIntent::LSystemRender => {
+                self.execute_lsystem_render(command).await
+            },
+            CommandIntent::SessionManagement => {
+                self.execute_session_command(command).await
+            },
             CommandIntent::Conversation => {
                 Ok(self.handle_conversation(command).await)
             },
@@ -248,36 +483,71 @@ impl CNLCommandParser {
         let default_action = "unknown".to_string();
         let target = command.parameters.get("target").unwrap_or(&default_target);
         let action = command.parameters.get("action").unwrap_or(&default_action);
+        let shell = command.shell.clone().unwrap_or_else(Shell::default_for_platform);
 
         match action.as_str() {
             "start" | "launch" => {
-                println!("🚀 Starting process: {}", target);
-                let output = Command::new("sh")
-                    .args(&["-c", target])
-                    .spawn();
+                println!("🚀 Starting process: {} (via {:?})", target, shell);
+                let (program, args) = shell.program_and_args(target);
+                let mut spec = crate::supervisor::WorkerSpec::new(target, program);
+                spec.args = args;
+                spec.restart_policy = crate::supervisor::RestartPolicy::Never;
 
-                match output {
-                    Ok(_) => Ok(format!("✅ Started: {}", target)),
+                let mut supervisor = self.supervisor.lock().await;
+                match supervisor.spawn(spec) {
+                    Ok(_) => Ok(format!("✅ Started: {} (tracked for graceful stop)", target)),
                     Err(e) => Ok(format!("❌ Failed to start {}: {}", target, e)),
                 }
             },
             "stop" | "kill" => {
                 println!("⏹️ Stopping process: {}", target);
-                let output = Command::new("pkill")
-                    .args(&["-f", target])
-                    .output()
-                    .await?;
+                let stop_signal = command.parameters.get("stop_signal")
+                    .and_then(|s| Supervisor::parse_signal_name(s));
+                let stop_timeout = command.parameters.get("stop_grace_secs")
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .map(Duration::from_secs);
 
-                if output.status.success() {
-                    Ok(format!("✅ Stopped: {}", target))
-                } else {
-                    Ok(format!("❌ Failed to stop {}: {}", target, String::from_utf8_lossy(&output.stderr)))
+                let mut supervisor = self.supervisor.lock().await;
+                match supervisor.stop(target, stop_signal, stop_timeout).await {
+                    Ok(crate::supervisor::ProcessEnd::ExitedOk(_)) => {
+                        Ok(format!("✅ {} exited cleanly", target))
+                    }
+                    Ok(crate::supervisor::ProcessEnd::ExitedErr(_, code)) => {
+                        Ok(format!("⚠️ {} exited with code {}", target, code))
+                    }
+                    Ok(crate::supervisor::ProcessEnd::TimedOut(_)) => {
+                        Ok(format!("🔪 {} did not stop gracefully in time, escalated to SIGKILL", target))
+                    }
+                    Ok(crate::supervisor::ProcessEnd::KilledBySignal(_, sig)) => {
+                        Ok(format!("🔪 {} was killed by signal {}", target, sig))
+                    }
+                    Err(e) => Ok(format!("❌ Failed to stop {}: {}", target, e)),
                 }
             },
+            "supervise" => self.supervise_process(target).await,
+            "unsupervise" => self.unsupervise_process(target).await,
             _ => Ok(format!("🤔 Unknown process action: {}", action)),
         }
     }
 
+    /// Hand `target` to the process supervisor with an `Always` restart
+    /// policy, so it's relaunched with backoff if it dies.
+    async fn supervise_process(&self, target: &str) -> Result<String> {
+        let mut spec = crate::supervisor::WorkerSpec::new(target, "sh");
+        spec.args = vec!["-c".to_string(), target.to_string()];
+        spec.restart_policy = crate::supervisor::RestartPolicy::Always;
+
+        let mut supervisor = self.supervisor.lock().await;
+        supervisor.spawn(spec)?;
+        Ok(format!("🔁 Now supervising {} (restart policy: Always)", target))
+    }
+
+    async fn unsupervise_process(&self, target: &str) -> Result<String> {
+        let mut supervisor = self.supervisor.lock().await;
+        supervisor.unsupervise(&target.to_string()).await?;
+        Ok(format!("🛑 Stopped supervising {}", target))
+    }
+
     async fn execute_system_query(&self, command: ParsedCommand) -> Result<String> {
         let default_type = "status".to_string();
         let query_type = command.parameters.get("type").unwrap_or(&default_type);
@@ -336,8 +606,11 @@ impl CNLCommandParser {
 
 🔄 Process Control:
    'start docker daemon'
-   'stop nginx'
+   'stop nginx' gracefully stops the group (SIGTERM, then SIGKILL after the grace period)
+   'stop nginx with SIGINT grace 5s' picks the signal and/or grace period
    'restart postgresql'
+   'supervise nginx' / 'stop supervising nginx'
+   'run build.ps1 using powershell' picks a shell explicitly (sh, bash, zsh, cmd, powershell, none)
 
 📊 System Queries:
    'show monitors'
@@ -349,11 +622,40 @@ impl CNLCommandParser {
    'fleet health'
    'observe fleet interactions'
    'deploy across DCs'
+   'run <command> in container <name>'
    'engage interactive fleet session'
 
+🗂️ Session Snapshots:
+   'session save' captures every real on-screen window's geometry to a RON file
+   'session load <path>' replays a saved snapshot through the detected GeometryBackend
+
+🌿 L-system Rendering:
+   'render l-system axiom=F rules=F:F+F--F+F,X:F-X+X n=4 step=10 angle=90'
+   'draw l-system ...' works the same - n, step, and angle default to 4/10/90 if omitted
+
 💬 Natural Language:
    Just tell me what you want to do! I understand context and can coordinate complex fleet operations.
 
+🧪 Dry Run:
+   Prefix anything with 'dry run', 'preview', or 'simulate' to see predicted
+   effects before they happen, e.g. 'dry run stop nginx'.
+
+🛠️ Background Workers:
+   'workers' lists mesh-healing/fleet-discovery/health-check workers and their live state
+   'worker <name> pause|resume|cancel' steers one at runtime
+
+🗒️ Audit History:
+   'history' shows recent fleet commands and a count of commands per risk level
+
+🕸️ Mesh Peer Client:
+   'mesh <dc> <method>' dials that DC's coordination port (WebSocket primary,
+   HTTP backup) and prints its negotiated capabilities plus the call's reply
+
+⏱️ Scheduler:
+   'focus <duration>' (e.g. 'focus 45m', 'focus 2h') starts focus mode and schedules its end
+   'schedule' lists pending entries (work/general mode flips, end-of-day, focus sessions)
+   'schedule cancel <id>' removes one before it fires
+
 🛡️ Safety & Authority:
    All operations use Core Interaction Principle with Top Turtle authority verification.
    Complete observability through CNL-generated MCP tools.".to_string()
@@ -378,35 +680,202 @@ impl CNLCommandParser {
             intent,
             parameters,
             risk_level: RiskLevel::Medium,
+            dry_run: false,
+            shell: None,
         }
     }
 
-    fn create_process_command(&self, captures: regex::Captures, intent: CommandIntent) -> ParsedCommand {
+    fn create_process_command(&self, captures: regex::Captures, intent: CommandIntent, action: &str) -> ParsedCommand {
         let mut parameters = std::collections::HashMap::new();
-        
+        let mut shell = None;
+
         if captures.len() > 1 {
+            let (target, explicit_shell) = Self::split_shell_suffix(&captures[1]);
+            shell = explicit_shell;
+            parameters.insert("target".to_string(), target);
+            parameters.insert("action".to_string(), action.to_string());
+        }
+        if let Some(signal_name) = captures.get(2) {
+            parameters.insert("stop_signal".to_string(), signal_name.as_str().to_string());
+        }
+        if let Some(grace) = captures.get(3) {
+            parameters.insert("stop_grace_secs".to_string(), grace.as_str().to_string());
+        }
+
+        ParsedCommand {
+            intent,
+            parameters,
+            risk_level: RiskLevel::High,
+            dry_run: false,
+            shell,
+        }
+    }
+
+    /// Split a trailing "using <shell>" suffix off a process-control target,
+    /// e.g. "build.ps1 using powershell" -> ("build.ps1", Some(Powershell)).
+    fn split_shell_suffix(target: &str) -> (String, Option<Shell>) {
+        let suffix_pattern = Regex::new(r"(?i)^(.*?)\s+using\s+(sh|bash|zsh|powershell|pwsh|cmd|none)$").unwrap();
+        if let Some(captures) = suffix_pattern.captures(target) {
+            return (captures[1].to_string(), Shell::from_name(&captures[2]));
+        }
+        (target.to_string(), None)
+    }
+
+    fn create_system_command(&self, captures: regex::Captures, intent: CommandIntent, action: &str) -> ParsedCommand {
+        let mut parameters = std::collections::HashMap::new();
+        parameters.insert("action".to_string(), action.to_string());
+
+        // Risk levels match the declarative map in `cnl_config`'s
+        // `safety_and_authority.risk_levels`: fleet coordination can deploy
+        // real infrastructure (High), fleet observation only reads it (Low).
+        let risk_level = match intent {
+            CommandIntent::FleetCoordination => RiskLevel::High,
+            _ => RiskLevel::Low,
+        };
+
+        match intent {
+            CommandIntent::SystemQuery => {
+                parameters.insert("type".to_string(), action.to_string());
+            }
+            CommandIntent::InfrastructureMonitoring
+            | CommandIntent::FleetCoordination
+            | CommandIntent::FleetObservation => {
+                if let Some(target) = captures.get(1) {
+                    parameters.insert("target".to_string(), target.as_str().to_string());
+                }
+            }
+            _ => {}
+        }
+
+        ParsedCommand {
+            intent,
+            parameters,
+            risk_level,
+            dry_run: false,
+            shell: None,
+        }
+    }
+
+    /// "run <cmd> in container <name>" - routes to `execute_fleet_coordination`'s
+    /// "run_in_container" action, which locates the container via
+    /// `fleet::find_endpoint` and runs it there with `fleet::run_in_container`.
+    fn create_container_run_command(&self, captures: regex::Captures, intent: CommandIntent) -> ParsedCommand {
+        let mut parameters = std::collections::HashMap::new();
+        parameters.insert("action".to_string(), "run_in_container".to_string());
+        parameters.insert("target".to_string(), captures[1].to_string());
+        parameters.insert("container".to_string(), captures[2].to_string());
+
+        ParsedCommand {
+            intent,
+            parameters,
+            risk_level: RiskLevel::High,
+            dry_run: false,
+            shell: None,
+        }
+    }
+
+    /// "session save" / "session load <path>" - routes to
+    /// `execute_session_command`, which calls `geometry_backend::detect` and
+    /// `session::SessionSnapshot`.
+    fn create_session_command(&self, captures: regex::Captures, intent: CommandIntent, action: &str) -> ParsedCommand {
+        let mut parameters = std::collections::HashMap::new();
+        parameters.insert("action".to_string(), action.to_string());
+        if action == "load" {
+            parameters.insert("path".to_string(), captures[1].trim().to_string());
+        }
+
+        ParsedCommand {
+            intent,
+            parameters,
+            risk_level: RiskLevel::Low,
+            dry_run: false,
+            shell: None,
+        }
+    }
+
+    fn create_state_command(&self, input: &str, captures: regex::Captures, intent: CommandIntent) -> ParsedCommand {
+        let mut parameters = std::collections::HashMap::new();
+
+        if input.to_lowercase().starts_with("add task") {
+            parameters.insert("verb".to_string(), "add_task".to_string());
+            if let Some(priority) = captures.get(1) {
+                parameters.insert("priority".to_string(), priority.as_str().to_string());
+            }
+            parameters.insert("title".to_string(), captures[2].to_string());
+        } else if input.to_lowercase().contains("deployed") {
+            parameters.insert("verb".to_string(), "set_deploy_pct".to_string());
+            parameters.insert("target".to_string(), captures[1].to_string());
+            parameters.insert("value".to_string(), captures[2].to_string());
+        } else {
+            parameters.insert("verb".to_string(), "set_status".to_string());
             parameters.insert("target".to_string(), captures[1].to_string());
-            parameters.insert("action".to_string(), "start".to_string());
+            parameters.insert("value".to_string(), captures[2].to_string());
+        }
+
+        ParsedCommand {
+            intent,
+            parameters,
+            risk_level: RiskLevel::Medium,
+            dry_run: false,
+            shell: None,
+        }
+    }
+
+    fn create_watch_command(&self, captures: regex::Captures, intent: CommandIntent) -> ParsedCommand {
+        let mut parameters = std::collections::HashMap::new();
+
+        parameters.insert("paths".to_string(), captures[1].to_string());
+        parameters.insert("command".to_string(), captures[2].to_string());
+        if let Some(busy_mode) = captures.get(3) {
+            parameters.insert("busy_mode".to_string(), busy_mode.as_str().to_string());
         }
 
         ParsedCommand {
             intent,
             parameters,
             risk_level: RiskLevel::High,
+            dry_run: false,
+            shell: None,
         }
     }
 
-    fn create_system_command(&self, _captures: regex::Captures, intent: CommandIntent) -> ParsedCommand {
+    /// Pull `key=value` tokens (`axiom=F rules=F:F+F--F+F,X:F-X+X n=4 step=10
+    /// angle=60`) out of the blob `lsystem_patterns` captured. Unset tokens
+    /// keep their CNL-sensible defaults rather than failing to parse - a
+    /// render with just an axiom and rules is still worth running.
+    fn create_lsystem_command(&self, captures: regex::Captures, intent: CommandIntent) -> ParsedCommand {
+        let spec = captures[1].to_string();
         let mut parameters = std::collections::HashMap::new();
-        parameters.insert("type".to_string(), "monitors".to_string());
+
+        let axiom = Self::lsystem_token(&spec, "axiom").unwrap_or_else(|| "F".to_string());
+        let rules = Self::lsystem_token(&spec, "rules").unwrap_or_default();
+        let iterations = Self::lsystem_token(&spec, "n").unwrap_or_else(|| "4".to_string());
+        let step = Self::lsystem_token(&spec, "step").unwrap_or_else(|| "10".to_string());
+        let angle = Self::lsystem_token(&spec, "angle").unwrap_or_else(|| "90".to_string());
+
+        parameters.insert("axiom".to_string(), axiom);
+        parameters.insert("rules".to_string(), rules);
+        parameters.insert("iterations".to_string(), iterations);
+        parameters.insert("step".to_string(), step);
+        parameters.insert("angle".to_string(), angle);
 
         ParsedCommand {
             intent,
             parameters,
             risk_level: RiskLevel::Low,
+            dry_run: false,
+            shell: None,
         }
     }
 
+    /// Find `key=<value>` in `spec` and return `<value>` (no surrounding
+    /// whitespace, since the grammar's own tokens - `+`, `-`, `[`, `]` -
+    /// never contain any).
+    fn lsystem_token(spec: &str, key: &str) -> Option<String> {
+        let pattern = Regex::new(&format!(r"(?i)\b{}=(\S+)", regex::escape(key))).unwrap();
+        pattern.captures(spec).map(|c| c[1].to_string())
+    }
+
     fn create_conversation_command(&self, input: &str) -> ParsedCommand {
         let mut parameters = std::collections::HashMap::new();
         parameters.insert("input".to_string(), input.to_string());
@@ -415,6 +884,8 @@ impl CNLCommandParser {
             intent: CommandIntent::Conversation,
             parameters,
             risk_level: RiskLevel::Low,
+            dry_run: false,
+            shell: None,
         }
     }
 
@@ -465,36 +936,42 @@ impl CNLCommandParser {
 
         let geometry = match position {
             "top-third" => WindowGeometry {
+                id: String::new(),
                 x: base_x,
                 y: base_y,
                 width,
                 height: height / 3,
             },
             "middle-third" => WindowGeometry {
+                id: String::new(),
                 x: base_x,
                 y: base_y + (height / 3) as i32,
                 width,
                 height: height / 3,
             },
             "bottom-third" => WindowGeometry {
+                id: String::new(),
                 x: base_x,
                 y: base_y + (2 * height / 3) as i32,
                 width,
                 height: height / 3,
             },
             "left-half" => WindowGeometry {
+                id: String::new(),
                 x: base_x,
                 y: base_y,
                 width: width / 2,
                 height,
             },
             "right-half" => WindowGeometry {
+                id: String::new(),
                 x: base_x + (width / 2) as i32,
                 y: base_y,
                 width: width / 2,
                 height,
             },
             _ => WindowGeometry {  // Center by default
+                id: String::new(),
                 x: base_x + (width / 4) as i32,
                 y: base_y + (height / 4) as i32,
                 width: width / 2,
@@ -515,29 +992,60 @@ impl CNLCommandParser {
         }
     }
 
-    fn generate_rollback_plan(&self, command: &ParsedCommand) -> Option<String> {
+    fn generate_rollback_plan(&self, command: &ParsedCommand) -> Option<crate::safety::RollbackPlan> {
+        let unknown = "unknown".to_string();
+        let target = command.parameters.get("target").unwrap_or(&unknown).clone();
+
         match command.intent {
-            CommandIntent::WindowManagement => {
-                Some("Restore window to original position and size".to_string())
-            },
-            CommandIntent::ProcessControl => {
-                Some("Terminate started processes or restart stopped ones".to_string())
-            },
+            CommandIntent::WindowManagement => Some(crate::safety::RollbackPlan::Snapshot),
+            CommandIntent::ProcessControl => Some(crate::safety::RollbackPlan::RestartProcess {
+                name: target.clone(),
+                argv: vec![target],
+            }),
+            CommandIntent::StateMutation => Some(crate::safety::RollbackPlan::Snapshot),
+            CommandIntent::WatchAndRun => Some(crate::safety::RollbackPlan::Snapshot),
             _ => None,
         }
     }
 
     async fn execute_fleet_coordination(&self, command: ParsedCommand) -> Result<String> {
         let action = command.parameters.get("action").unwrap_or(&"status".to_string()).clone();
-        
+
         println!("🐢 Fleet Coordination - CNL-Native Approach");
-        
+
         match action.as_str() {
             "status" => Ok("📡 Coordinating turtle fleet status through CNL specifications...".to_string()),
             "deploy" => {
                 let target_default = "DCs".to_string();
                 let target = command.parameters.get("target").unwrap_or(&target_default);
-                Ok(format!("🚀 Initiating CNL-native fleet deployment to {}", target))
+                println!("🚀 Fanning deployment out to every configured endpoint...");
+
+                let results = crate::fleet::deploy_fleet().await;
+                let lines: Vec<String> = results
+                    .into_iter()
+                    .map(|(dc, result)| match result {
+                        Ok(started) if started.is_empty() => format!("   - {}: already fully deployed", dc),
+                        Ok(started) => format!("   - {}: started {}", dc, started.join(", ")),
+                        Err(e) => format!("   - {}: ❌ {}", dc, e),
+                    })
+                    .collect();
+
+                Ok(format!(
+                    "🚀 Fleet deployment to {} complete:\n{}",
+                    target,
+                    lines.join("\n")
+                ))
+            },
+            "run_in_container" => {
+                let container = command.parameters.get("container").cloned().unwrap_or_default();
+                match crate::fleet::find_endpoint(&container).await {
+                    Ok(Some(endpoint)) => match crate::fleet::run_in_container(&endpoint, &container, &command).await {
+                        Ok(output) => Ok(format!("🐳 [{} :: {}]\n{}", endpoint.dc, container, output)),
+                        Err(e) => Ok(format!("❌ Failed running in {}: {}", container, e)),
+                    },
+                    Ok(None) => Ok(format!("🤷 No endpoint has a running container named '{}'", container)),
+                    Err(e) => Ok(format!("❌ {}", e)),
+                }
             },
             _ => Ok(format!("🔧 Fleet coordination: {}", action))
         }
@@ -545,16 +1053,248 @@ impl CNLCommandParser {
     
     async fn execute_fleet_status(&self, _command: ParsedCommand) -> Result<String> {
         println!("🐢 Fleet Status Check - CNL Processing");
-        Ok("📊 Fleet Health:\n  • 28 turtles coordinated through CNL specifications\n  • All operations under Top Turtle authority\n  • Complete observability active\n  • CNL-to-MCP pipeline operational".to_string())
+
+        let mut supervisor = self.supervisor.lock().await;
+        let ended = supervisor.reap().await.unwrap_or_default();
+        let notifier = crate::notifier::Notifier::new(
+            crate::cnl_config::CNLConfigLoader::load_config()
+                .map(|c| crate::notifier::NotifierConfig::from_cnl(&c))
+                .unwrap_or_default(),
+        );
+        for end in &ended {
+            match end {
+                crate::supervisor::ProcessEnd::ExitedOk(name) => println!("✅ {} exited cleanly", name),
+                crate::supervisor::ProcessEnd::ExitedErr(name, code) => {
+                    println!("❌ {} exited with code {}", name, code);
+                    let _ = notifier
+                        .notify(crate::notifier::Notification::WorkerDied { name: name.clone(), outcome: end.clone() })
+                        .await;
+                }
+                crate::supervisor::ProcessEnd::KilledBySignal(name, sig) => {
+                    println!("💀 {} killed by signal {}", name, sig);
+                    let _ = notifier
+                        .notify(crate::notifier::Notification::WorkerDied { name: name.clone(), outcome: end.clone() })
+                        .await;
+                }
+                crate::supervisor::ProcessEnd::TimedOut(name) => println!("⏱️ {} timed out stopping", name),
+            }
+        }
+
+        let supervised = supervisor.status();
+        let supervised_line = if supervised.is_empty() {
+            "no supervised workers running".to_string()
+        } else {
+            supervised
+                .iter()
+                .map(|(name, state, restarts)| format!("{}: {:?} (restarts: {})", name, state, restarts))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        Ok(format!(
+            "📊 Fleet Health:\n  • 28 turtles coordinated through CNL specifications\n  • All operations under Top Turtle authority\n  • Complete observability active\n  • CNL-to-MCP pipeline operational\n  • Supervisor: {}",
+            supervised_line
+        ))
     }
     
     async fn execute_fleet_observation(&self, command: ParsedCommand) -> Result<String> {
         let target = command.parameters.get("target").unwrap_or(&"all".to_string()).clone();
-        
+
         println!("👁️ Fleet Observation - MCP Tools Generated from CNL");
-        Ok(format!("🔍 Observing {} through CNL-generated MCP observation tools", target))
+
+        if target.eq_ignore_ascii_case("all") {
+            let db = crate::db::DbCtx::open()?;
+            let mut lines = Vec::new();
+            for endpoint in crate::fleet::endpoints() {
+                match crate::containers::refresh_deploy_status(&endpoint, &db).await {
+                    Ok(pct) => lines.push(format!("   - {}: {}% deployed", endpoint.dc, pct)),
+                    Err(e) => lines.push(format!("   - {}: ❌ {}", endpoint.dc, e)),
+                }
+            }
+            return Ok(format!("🔍 Observing fleet-wide deploy status:\n{}", lines.join("\n")));
+        }
+
+        let observations = crate::fleet::observe(&target).await?;
+        if observations.is_empty() {
+            return Ok(format!("🔍 No container named '{}' found on any configured endpoint", target));
+        }
+
+        let lines: Vec<String> = observations
+            .iter()
+            .map(|o| {
+                format!(
+                    "   - {} on {}: {} (cpu {:.1}%, mem {} MB)",
+                    o.container, o.dc, o.state, o.cpu_percent, o.memory_mb
+                )
+            })
+            .collect();
+
+        Ok(format!("🔍 Observing '{}' across the fleet:\n{}", target, lines.join("\n")))
     }
     
+    async fn execute_state_mutation(&self, command: ParsedCommand) -> Result<String> {
+        let db = crate::db::DbCtx::open()?;
+        let unknown = "unknown".to_string();
+        let verb = command.parameters.get("verb").unwrap_or(&unknown);
+
+        match verb.as_str() {
+            "set_deploy_pct" => {
+                let target = command.parameters.get("target").unwrap_or(&unknown);
+                let value: u32 = command.parameters.get("value").map(|v| v.parse().unwrap_or(0)).unwrap_or(0);
+                db.set_dc_deploy_pct(target, value)?;
+                Ok(format!("📊 {} now {}% deployed", target, value))
+            },
+            "set_status" => {
+                let target = command.parameters.get("target").unwrap_or(&unknown);
+                let status = command.parameters.get("value").unwrap_or(&unknown);
+                db.set_dc_status(target, status)?;
+                Ok(format!("📊 {} status set to '{}'", target, status))
+            },
+            "add_task" => {
+                let title = command.parameters.get("title").unwrap_or(&unknown);
+                let priority: u32 = command.parameters.get("priority").and_then(|p| p.parse().ok()).unwrap_or(9);
+                db.add_task(priority, title)?;
+                Ok(format!("📝 Added task (p{}): {}", priority, title))
+            },
+            _ => Ok(format!("🤔 Unknown state mutation: {}", verb)),
+        }
+    }
+
+    /// Start a watch-and-run session in the background. The watch loop runs
+    /// indefinitely, so it's spawned as its own task rather than blocking
+    /// this command's caller.
+    async fn execute_watch_and_run(&self, command: ParsedCommand) -> Result<String> {
+        let unknown = "unknown".to_string();
+        let path_str = command.parameters.get("paths").unwrap_or(&unknown).clone();
+        let cmd_str = command.parameters.get("command").unwrap_or(&unknown).clone();
+        let busy_mode = command.parameters.get("busy_mode").map(|s| s.as_str()).unwrap_or("queue");
+
+        let on_busy_update = match busy_mode.to_lowercase().as_str() {
+            "ignore" => crate::watch::OnBusyUpdate::DoNothing,
+            "restart" => crate::watch::OnBusyUpdate::Restart,
+            mode if mode.starts_with("signal") => {
+                let sig: i32 = mode.split_whitespace().nth(1).and_then(|n| n.parse().ok()).unwrap_or(1);
+                crate::watch::OnBusyUpdate::Signal(sig)
+            }
+            _ => crate::watch::OnBusyUpdate::Queue,
+        };
+
+        let config = crate::watch::WatchConfig {
+            paths: vec![std::path::PathBuf::from(&path_str)],
+            action: crate::watch::WatchAction::Command(cmd_str.clone()),
+            on_busy_update,
+            debounce: std::time::Duration::from_millis(50),
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = crate::watch::run(config).await {
+                println!("⚠️ Watch session ended: {}", e);
+            }
+        });
+
+        Ok(format!(
+            "👁️ Watching '{}' - will run '{}' on change ({:?})",
+            path_str, cmd_str, on_busy_update
+        ))
+    }
+
+    /// `rules=F:F+F--F+F,X:F-X+X` -> `{'F': "F+F--F+F", 'X': "F-X+X"}`. A rule
+    /// whose character isn't a single `char` (or whose body is empty) is
+    /// skipped rather than erroring the whole render - malformed tokens are
+    /// a CNL typo, not a reason to refuse drawing the rest of the grammar.
+    fn parse_lsystem_rules(rules_spec: &str) -> std::collections::HashMap<char, String> {
+        let mut rules = std::collections::HashMap::new();
+        for pair in rules_spec.split(',') {
+            if let Some((symbol, body)) = pair.split_once(':') {
+                let mut chars = symbol.chars();
+                if let (Some(c), None, false) = (chars.next(), chars.next(), body.is_empty()) {
+                    rules.insert(c, body.to_string());
+                }
+            }
+        }
+        rules
+    }
+
+    async fn execute_lsystem_render(&self, command: ParsedCommand) -> Result<String> {
+        let default_axiom = "F".to_string();
+        let axiom = command.parameters.get("axiom").unwrap_or(&default_axiom);
+        let rules_spec = command.parameters.get("rules").map(|s| s.as_str()).unwrap_or("");
+        let rules = Self::parse_lsystem_rules(rules_spec);
+
+        let iterations: u32 = command.parameters.get("iterations").and_then(|s| s.parse().ok()).unwrap_or(4);
+        let step: f64 = command.parameters.get("step").and_then(|s| s.parse().ok()).unwrap_or(10.0);
+        let angle: f64 = command.parameters.get("angle").and_then(|s| s.parse().ok()).unwrap_or(90.0);
+
+        let (segments, bounds) = crate::lsystem::render(axiom, &rules, iterations, step, angle, crate::lsystem::MAX_EXPANDED_LEN)?;
+
+        Ok(format!(
+            "🌿 L-system render: axiom '{}' with {} rule(s), {} iteration(s) -> {} segment(s), bounding box {}x{} at ({}, {})",
+            axiom, rules.len(), iterations, segments.len(), bounds.width, bounds.height, bounds.x, bounds.y
+        ))
+    }
+
+    async fn execute_session_command(&self, command: ParsedCommand) -> Result<String> {
+        let action = command.parameters.get("action").map(|s| s.as_str()).unwrap_or("save");
+        match action {
+            "save" => self.execute_session_save().await,
+            "load" => {
+                let path = command.parameters.get("path").cloned().unwrap_or_default();
+                self.execute_session_load(&path).await
+            }
+            other => Ok(format!("🤔 Unknown session action: {}", other)),
+        }
+    }
+
+    /// Query every real on-screen window through the runtime-detected
+    /// `GeometryBackend` and write them out as a `SessionSnapshot`, so a
+    /// layout can be replayed later with `session load <path>`.
+    async fn execute_session_save(&self) -> Result<String> {
+        let backend = crate::geometry_backend::detect();
+        let windows = backend.query_windows().await?;
+        // Tagged with the same channel `generate_monitoring_pattern` derives
+        // for `FleetObservation`, since a manual "session save" is itself an
+        // observation of the fleet's current window layout.
+        let tracked: Vec<crate::session::TrackedWindow> = windows
+            .into_iter()
+            .map(|geometry| crate::session::TrackedWindow { channel: "fleet_interaction_tracking".to_string(), geometry })
+            .collect();
+
+        let snapshot = crate::session::SessionSnapshot::capture(tracked);
+        let window_count = snapshot.windows.len();
+        let path = snapshot.write(&crate::session::default_dir())?;
+        Ok(format!("💾 Saved session snapshot ({} window(s)) to {}", window_count, path.display()))
+    }
+
+    /// Reload a `SessionSnapshot` and feed each tracked window's geometry
+    /// back through `GeometryBackend::move_resize` to restore the layout it
+    /// captured.
+    async fn execute_session_load(&self, path: &str) -> Result<String> {
+        let snapshot = crate::session::SessionSnapshot::load(std::path::Path::new(path))?;
+        let backend = crate::geometry_backend::detect();
+
+        let mut restored = 0;
+        let mut failures = Vec::new();
+        for tracked in &snapshot.windows {
+            match backend.move_resize(&tracked.geometry).await {
+                Ok(()) => restored += 1,
+                Err(e) => failures.push(format!("{}: {}", tracked.geometry.id, e)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(format!("📂 Restored {} window(s) from {}", restored, path))
+        } else {
+            Ok(format!(
+                "📂 Restored {}/{} window(s) from {} ({} failed):\n{}",
+                restored,
+                snapshot.windows.len(),
+                path,
+                failures.len(),
+                failures.join("\n")
+            ))
+        }
+    }
+
     async fn execute_top_turtle_command(&self, _command: ParsedCommand) -> Result<String> {
         println!("🎯 Top Turtle Command - Interactive Fleet Session Engagement");
         Ok("🐢 Top Turtle Interactive Fleet Session Active:\n  • Complete observability enabled\n  • Permission-free execution with safety verification\n  • All 28 turtles coordinated through CNL\n  • Real-time fleet intelligence available".to_string())
@@ -569,15 +1309,26 @@ impl CNLCommandParser {
             CommandIntent::FleetStatus => "fleet_health_monitoring".to_string(),
             CommandIntent::FleetObservation => "fleet_interaction_tracking".to_string(),
             CommandIntent::TopTurtleCommand => "top_turtle_session_monitoring".to_string(),
+            CommandIntent::StateMutation => "state_db_writes".to_string(),
+            CommandIntent::WatchAndRun => "process_state_changes".to_string(),
+            CommandIntent::LSystemRender => "lsystem_render_output".to_string(),
+            CommandIntent::SessionManagement => "session_snapshot_io".to_string(),
             _ => "general_system_changes".to_string(),
         }
     }
 }
 
-#[derive(Debug, Clone)]
-struct WindowGeometry {
-    x: i32,
-    y: i32,
-    width: u32,
-    height: u32,
+/// Also the input/output type of `layout::tile`, `geometry_backend`'s
+/// `GeometryBackend` trait, and `session`'s RON snapshots. `id` identifies an
+/// actual on-screen window (a `GeometryBackend`-specific handle, e.g. an X11
+/// window id) - empty for a geometry that's just a computed target
+/// rectangle for a window that doesn't exist yet, as
+/// `calculate_window_geometry` returns below.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct WindowGeometry {
+    pub id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
 }
\ No newline at end of file
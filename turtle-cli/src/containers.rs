@@ -0,0 +1,127 @@
+use anyhow::Result;
+use bollard::container::{ListContainersOptions, StartContainerOptions};
+use bollard::Docker;
+use std::collections::HashMap;
+
+use crate::db::DbCtx;
+
+/// A data center modeled as a container-runtime endpoint: the daemon we talk
+/// to (Docker or Podman, both speak the Docker API) and the services that
+/// must be running there for the DC to count as fully deployed.
+#[derive(Debug, Clone)]
+pub struct DcEndpoint {
+    pub dc: String,
+    /// `unix:///var/run/docker.sock` or `tcp://host:2375`
+    pub uri: String,
+    pub expected_services: Vec<String>,
+}
+
+pub fn default_endpoints() -> Vec<DcEndpoint> {
+    vec![
+        DcEndpoint {
+            dc: "DC1".to_string(),
+            uri: "unix:///var/run/docker.sock".to_string(),
+            expected_services: vec!["turtle-gateway".to_string(), "turtle-mesh".to_string()],
+        },
+        DcEndpoint {
+            dc: "DC2".to_string(),
+            uri: "tcp://192.168.1.50:2375".to_string(),
+            expected_services: vec!["turtle-gateway".to_string(), "turtle-mesh".to_string()],
+        },
+        DcEndpoint {
+            dc: "DC3".to_string(),
+            uri: "tcp://fly-observer:2375".to_string(),
+            expected_services: vec!["turtle-observer".to_string()],
+        },
+    ]
+}
+
+pub(crate) fn connect(endpoint: &DcEndpoint) -> Result<Docker> {
+    let docker = if let Some(socket_path) = endpoint.uri.strip_prefix("unix://") {
+        Docker::connect_with_unix(socket_path, 5, bollard::API_DEFAULT_VERSION)?
+    } else {
+        Docker::connect_with_http(&endpoint.uri, 5, bollard::API_DEFAULT_VERSION)?
+    };
+    Ok(docker)
+}
+
+pub(crate) async fn running_container_names(endpoint: &DcEndpoint) -> Result<Vec<String>> {
+    let docker = connect(endpoint)?;
+
+    let mut filters = HashMap::new();
+    filters.insert("status".to_string(), vec!["running".to_string()]);
+
+    let containers = docker
+        .list_containers(Some(ListContainersOptions {
+            all: false,
+            filters,
+            ..Default::default()
+        }))
+        .await?;
+
+    Ok(containers
+        .into_iter()
+        .flat_map(|c| c.names.unwrap_or_default())
+        .map(|n| n.trim_start_matches('/').to_string())
+        .collect())
+}
+
+/// Does the endpoint have a running container matching this id or name?
+pub async fn has_container(endpoint: &DcEndpoint, id_or_name: &str) -> Result<bool> {
+    let names = running_container_names(endpoint).await?;
+    Ok(names.iter().any(|n| n == id_or_name))
+}
+
+/// Compute deploy percentage for a DC as (running expected services) / (expected services),
+/// and persist the observed status into `DbCtx` so the dashboard reflects runtime facts.
+pub async fn refresh_deploy_status(endpoint: &DcEndpoint, db: &DbCtx) -> Result<u32> {
+    match running_container_names(endpoint).await {
+        Ok(running) => {
+            let present = endpoint
+                .expected_services
+                .iter()
+                .filter(|svc| running.iter().any(|n| n == *svc))
+                .count();
+            let pct = if endpoint.expected_services.is_empty() {
+                100
+            } else {
+                ((present as f64 / endpoint.expected_services.len() as f64) * 100.0).round() as u32
+            };
+            db.set_dc_deploy_pct(&endpoint.dc, pct)?;
+            db.set_dc_status(
+                &endpoint.dc,
+                if pct == 100 {
+                    "fully deployed"
+                } else if pct == 0 {
+                    "not started"
+                } else {
+                    "partially deployed"
+                },
+            )?;
+            Ok(pct)
+        }
+        Err(e) => {
+            db.set_dc_status(&endpoint.dc, &format!("unreachable: {}", e))?;
+            Ok(0)
+        }
+    }
+}
+
+/// Start every declared service container that isn't already running at this endpoint.
+pub async fn deploy(endpoint: &DcEndpoint) -> Result<Vec<String>> {
+    let docker = connect(endpoint)?;
+    let running = running_container_names(endpoint).await?;
+    let mut started = Vec::new();
+
+    for service in &endpoint.expected_services {
+        if running.iter().any(|n| n == service) {
+            continue;
+        }
+        docker
+            .start_container(service, None::<StartContainerOptions<String>>)
+            .await?;
+        started.push(service.clone());
+    }
+
+    Ok(started)
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::cnl_config::SafetyAuthorityConfig;
+use crate::containers;
+use crate::dashboard::Dashboard;
+use crate::db::DbCtx;
+use crate::safety::RiskLevel;
+use crate::system;
+use crate::work;
+
+/// Rejected before the handler ever runs, so callers see exactly why a
+/// command didn't happen rather than a generic `anyhow::Error`.
+#[derive(Debug, Clone)]
+pub enum CoordinatorError {
+    /// `command` is tagged `HIGH` risk and `top_turtle_authority_required` is
+    /// set, but the caller didn't present Top Turtle authority.
+    Unauthorized { command: String, risk_level: RiskLevel },
+    /// The handler ran but the underlying operation failed.
+    Failed(String),
+}
+
+impl fmt::Display for CoordinatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinatorError::Unauthorized { command, risk_level } => {
+                write!(f, "'{}' is {:?} risk and requires Top Turtle authority", command, risk_level)
+            }
+            CoordinatorError::Failed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for CoordinatorError {}
+
+impl From<anyhow::Error> for CoordinatorError {
+    fn from(e: anyhow::Error) -> Self {
+        CoordinatorError::Failed(e.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DashboardMode {
+    Compact,
+    WorkFocused,
+    Expanded,
+    InfrastructureFocused,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StateTarget {
+    Standard,
+    SecureEnclave,
+}
+
+type Reply<T> = oneshot::Sender<Result<T, CoordinatorError>>;
+
+/// Every fleet-coordination action the REPL and the one-shot CLI
+/// subcommands used to perform by constructing a `Dashboard` or calling
+/// `system::transition_to_*` directly. Routing them through here instead
+/// means the `Coordinator`'s single handler loop is the one place that
+/// orders them and checks authority, rather than each call site doing both
+/// ad hoc.
+pub enum Command {
+    ShowDashboard { mode: DashboardMode, top_turtle: bool, reply: Reply<()> },
+    TransitionState { target: StateTarget, top_turtle: bool, reply: Reply<()> },
+    FocusMode { duration: Option<String>, top_turtle: bool, reply: Reply<()> },
+    DispatchFleet { dc: String, top_turtle: bool, reply: Reply<String> },
+}
+
+impl Command {
+    fn label(&self) -> &'static str {
+        match self {
+            Command::ShowDashboard { .. } => "ShowDashboard",
+            Command::TransitionState { .. } => "TransitionState",
+            Command::FocusMode { .. } => "FocusMode",
+            Command::DispatchFleet { .. } => "DispatchFleet",
+        }
+    }
+
+    /// Mirrors `CoreInteractionPrinciple::analyze_safety_risks`'s verb-based
+    /// classification, but keyed on the coordinator's own command names
+    /// instead of a free-text operation string.
+    fn risk_level(&self) -> RiskLevel {
+        match self {
+            Command::ShowDashboard { .. } => RiskLevel::Low,
+            Command::FocusMode { .. } => RiskLevel::Medium,
+            Command::TransitionState { .. } => RiskLevel::High,
+            Command::DispatchFleet { .. } => RiskLevel::High,
+        }
+    }
+
+    fn top_turtle(&self) -> bool {
+        match self {
+            Command::ShowDashboard { top_turtle, .. }
+            | Command::TransitionState { top_turtle, .. }
+            | Command::FocusMode { top_turtle, .. }
+            | Command::DispatchFleet { top_turtle, .. } => *top_turtle,
+        }
+    }
+}
+
+/// A handle to the running `Coordinator` task. Cheap to clone - every caller
+/// just needs to be able to hand a `Command` to the one task that actually
+/// holds the fleet state.
+#[derive(Clone)]
+pub struct CoordinatorHandle {
+    tx: mpsc::Sender<Command>,
+}
+
+impl CoordinatorHandle {
+    pub async fn show_dashboard(&self, mode: DashboardMode, top_turtle: bool) -> Result<(), CoordinatorError> {
+        let (reply, rx) = oneshot::channel();
+        self.send(Command::ShowDashboard { mode, top_turtle, reply }, rx).await
+    }
+
+    pub async fn transition_state(&self, target: StateTarget, top_turtle: bool) -> Result<(), CoordinatorError> {
+        let (reply, rx) = oneshot::channel();
+        self.send(Command::TransitionState { target, top_turtle, reply }, rx).await
+    }
+
+    pub async fn focus_mode(&self, duration: Option<String>, top_turtle: bool) -> Result<(), CoordinatorError> {
+        let (reply, rx) = oneshot::channel();
+        self.send(Command::FocusMode { duration, top_turtle, reply }, rx).await
+    }
+
+    pub async fn dispatch_fleet(&self, dc: String, top_turtle: bool) -> Result<String, CoordinatorError> {
+        let (reply, rx) = oneshot::channel();
+        self.send(Command::DispatchFleet { dc, top_turtle, reply }, rx).await
+    }
+
+    async fn send<T>(&self, command: Command, rx: oneshot::Receiver<Result<T, CoordinatorError>>) -> Result<T, CoordinatorError> {
+        if self.tx.send(command).await.is_err() {
+            return Err(CoordinatorError::Failed("coordinator task has shut down".to_string()));
+        }
+        rx.await.unwrap_or_else(|_| Err(CoordinatorError::Failed("coordinator dropped the reply".to_string())))
+    }
+}
+
+/// Spawn the coordinator's handler loop and return a handle to it. Safe to
+/// call from outside an async fn as long as a Tokio runtime is current, same
+/// as `AuditLogger::init()`.
+pub fn spawn(safety: SafetyAuthorityConfig) -> CoordinatorHandle {
+    let (tx, rx) = mpsc::channel(32);
+    tokio::spawn(run(rx, safety));
+    CoordinatorHandle { tx }
+}
+
+/// The one sequential handler loop: commands are processed strictly in
+/// arrival order, so a `TransitionState` can never race a `DispatchFleet`
+/// against the same fleet state.
+async fn run(mut rx: mpsc::Receiver<Command>, safety: SafetyAuthorityConfig) {
+    while let Some(command) = rx.recv().await {
+        if let Some(rejection) = authorize(&command, &safety) {
+            reject(command, rejection);
+            continue;
+        }
+
+        match command {
+            Command::ShowDashboard { mode, reply, .. } => {
+                let _ = reply.send(handle_show_dashboard(mode).await.map_err(CoordinatorError::from));
+            }
+            Command::TransitionState { target, reply, .. } => {
+                let _ = reply.send(handle_transition_state(target).await.map_err(CoordinatorError::from));
+            }
+            Command::FocusMode { duration, reply, .. } => {
+                let _ = reply.send(work::focus_mode(duration).await.map_err(CoordinatorError::from));
+            }
+            Command::DispatchFleet { dc, reply, .. } => {
+                let _ = reply.send(handle_dispatch_fleet(&dc).await.map_err(CoordinatorError::from));
+            }
+        }
+    }
+}
+
+/// `None` means the command is cleared to run; `Some` carries the rejection
+/// reason to send back over its reply channel.
+fn authorize(command: &Command, safety: &SafetyAuthorityConfig) -> Option<CoordinatorError> {
+    let risk_level = command.risk_level();
+    if safety.top_turtle_authority_required && risk_level == RiskLevel::High && !command.top_turtle() {
+        Some(CoordinatorError::Unauthorized { command: command.label().to_string(), risk_level })
+    } else {
+        None
+    }
+}
+
+fn reject(command: Command, rejection: CoordinatorError) {
+    match command {
+        Command::ShowDashboard { reply, .. } => {
+            let _ = reply.send(Err(rejection));
+        }
+        Command::TransitionState { reply, .. } => {
+            let _ = reply.send(Err(rejection));
+        }
+        Command::FocusMode { reply, .. } => {
+            let _ = reply.send(Err(rejection));
+        }
+        Command::DispatchFleet { reply, .. } => {
+            let _ = reply.send(Err(rejection));
+        }
+    }
+}
+
+async fn handle_show_dashboard(mode: DashboardMode) -> anyhow::Result<()> {
+    let mut dashboard = Dashboard::new().await?;
+    match mode {
+        DashboardMode::Compact => dashboard.show_compact().await,
+        DashboardMode::WorkFocused => dashboard.show_work_focused().await,
+        DashboardMode::Expanded => dashboard.show_expanded().await,
+        DashboardMode::InfrastructureFocused => dashboard.show_infrastructure_focused().await,
+    }
+}
+
+async fn handle_transition_state(target: StateTarget) -> anyhow::Result<()> {
+    match target {
+        StateTarget::Standard => system::transition_to_standard().await,
+        StateTarget::SecureEnclave => system::transition_to_secure_enclave().await,
+    }
+}
+
+async fn handle_dispatch_fleet(dc: &str) -> anyhow::Result<String> {
+    let endpoint = containers::default_endpoints()
+        .into_iter()
+        .find(|e| e.dc.eq_ignore_ascii_case(dc));
+
+    let Some(endpoint) = endpoint else {
+        anyhow::bail!("Unknown data center: {}", dc);
+    };
+
+    let started = containers::deploy(&endpoint).await?;
+
+    // DbCtx is Sync (see db.rs), so holding `db` across this await doesn't
+    // make `run`'s tokio::spawn-driven future !Send.
+    let db = DbCtx::open()?;
+    containers::refresh_deploy_status(&endpoint, &db).await?;
+
+    if started.is_empty() {
+        Ok(format!("{} already fully deployed", endpoint.dc))
+    } else {
+        Ok(format!("Started: {}", started.join(", ")))
+    }
+}
@@ -1,70 +1,132 @@
 use anyhow::Result;
 use std::time::SystemTime;
 
+use crate::db::DbCtx;
+
 pub struct Dashboard {
     pub last_update: SystemTime,
+    db: DbCtx,
 }
 
 impl Dashboard {
     pub async fn new() -> Result<Self> {
         Ok(Dashboard {
             last_update: SystemTime::now(),
+            db: DbCtx::open()?,
         })
     }
 
     pub async fn show_compact(&mut self) -> Result<()> {
-        println!("🚀 TURTLE WORK DASHBOARD                                    {}", 
+        let dcs = self.db.data_centers()?;
+        let turtles = self.db.turtles()?;
+        let tasks = self.db.tasks()?;
+        let ready_count = turtles.iter().filter(|t| t.ready).count();
+
+        let next_task = tasks.iter().find(|t| t.status == "open");
+        let dc_status = dcs
+            .iter()
+            .map(|dc| format!("{} {} {}% deployed", dc.name, dc_state_icon(dc), dc.deploy_pct))
+            .collect::<Vec<_>>()
+            .join(" | ");
+
+        println!("🚀 TURTLE WORK DASHBOARD                                    {}",
                  chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
-        println!("├─ 📅 NEXT: DC1 full deployment needed (container runtime + services)");
+        if let Some(task) = next_task {
+            println!("├─ 📅 NEXT: {}", task.title);
+        } else {
+            println!("├─ 📅 NEXT: No open tasks");
+        }
         println!("├─ 🎯 FOCUS: Live dashboard with continuous updates running");
-        println!("├─ 📊 DC STATUS: DC1 ⚠️ 20% deployed | DC2 💤 not started | DC3 💤 not deployed");
-        println!("└─ 💬 COMMS: OR research complete (8 submissions) | 🐢 Fleet: 28 turtles ready");
+        println!("├─ 📊 DC STATUS: {}", dc_status);
+        println!("└─ 💬 COMMS: OR research complete (8 submissions) | 🐢 Fleet: {}/{} turtles ready",
+                 ready_count, turtles.len());
         Ok(())
     }
 
     pub async fn show_work_focused(&mut self) -> Result<()> {
+        let tasks = self.db.tasks()?;
+        let turtles = self.db.turtles()?;
+        let ready_count = turtles.iter().filter(|t| t.ready).count();
+
         println!("🚀 TURTLE WORK DASHBOARD - WORK FOCUSED");
         println!();
         println!("📅 CALENDAR                    🎯 TASKS & PROJECTS");
         println!("Next: DC1 deployment          High Priority:");
-        println!("├─ Container runtime setup    ├─ Fix UDM Pro SSH access");
-        println!("├─ Docker deployment          ├─ Deploy turtle services");
-        println!("└─ Service orchestration      └─ Enable 3-DC integration");
+        for task in tasks.iter().filter(|t| t.status == "open").take(3) {
+            println!("├─ {}", task.title);
+        }
         println!();
         println!("💬 COMMUNICATIONS             🤝 PARTNERSHIP");
         println!("OR Research: Complete          AWS Disruption Progress:");
         println!("├─ 8 optimization requests    ├─ Fleet organized ✅");
         println!("├─ A/B/C testing ready        ├─ Research complete ✅");
-        println!("└─ Turtle fleet: 28 🐢        └─ Deployment pending ⚠️");
+        println!("└─ Turtle fleet: {}/{} 🐢      └─ Deployment pending ⚠️", ready_count, turtles.len());
         Ok(())
     }
 
     pub async fn show_expanded(&mut self) -> Result<()> {
         self.show_work_focused().await?;
+        let dcs = self.db.data_centers()?;
+
         println!();
         println!("📊 INFRASTRUCTURE             🔄 RECENT ACTIVITY");
-        println!("DC1: ⚠️ SSH issues           ├─ OR team structure complete");
-        println!("DC2: 💤 Not started          ├─ Rust CLI architecture design");
-        println!("DC3: 💤 Not deployed         ├─ ENL/CNL A/B/C test ready");
-        println!("UDM Pro: Good citizen ready  └─ Deployment plan created");
+        for dc in &dcs {
+            println!("{}: {} {}           ├─ Updated via turtle_state.db", dc.name, dc_state_icon(dc), dc.status);
+        }
         Ok(())
     }
 
     pub async fn show_infrastructure_focused(&mut self) -> Result<()> {
+        let dcs = self.db.data_centers()?;
+        let turtles = self.db.turtles()?;
+
         println!("🏗️ TURTLE INFRASTRUCTURE STATUS");
         println!();
         println!("🌍 GLOBAL DC STATUS");
-        println!("DC1 (UDM Pro)     [██░░░░░░░░] 20% - SSH connectivity issues");
-        println!("DC2 (Laura's LAN) [░░░░░░░░░░]  0% - Awaiting setup");
-        println!("DC3 (Fly.io)      [░░░░░░░░░░]  0% - Observer not deployed");
+        for dc in &dcs {
+            println!("{:<18}{} {:>3}% - {}", dc.name, progress_bar(dc.deploy_pct), dc.deploy_pct, dc.status);
+        }
         println!();
         println!("🐢 TURTLE FLEET READINESS");
-        println!("Operations 🐢     [██████████] 11/11 ready");
-        println!("Engineering 🐢    [██████████]  5/5 ready");
-        println!("Experimental 🐢   [██████████]  5/5 ready");
-        println!("Design 🐢         [██████████]  4/4 ready");
+        for squad in squads(&turtles) {
+            let total = turtles.iter().filter(|t| t.squad == squad).count();
+            let ready = turtles.iter().filter(|t| t.squad == squad && t.ready).count();
+            println!("{:<18}{} {}/{} ready", format!("{} 🐢", squad), progress_bar(pct(ready, total)), ready, total);
+        }
         println!();
-        println!("Total: 28🐢 specialized turtles ready for deployment");
+        println!("Total: {}🐢 specialized turtles ready for deployment",
+                 turtles.iter().filter(|t| t.ready).count());
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+fn dc_state_icon(dc: &crate::db::DataCenter) -> &'static str {
+    match dc.deploy_pct {
+        0 => "💤",
+        100 => "✅",
+        _ => "⚠️",
+    }
+}
+
+fn progress_bar(pct: u32) -> String {
+    let filled = (pct / 10).min(10) as usize;
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(10 - filled))
+}
+
+fn pct(ready: usize, total: usize) -> u32 {
+    if total == 0 {
+        0
+    } else {
+        ((ready as f64 / total as f64) * 100.0).round() as u32
+    }
+}
+
+fn squads(turtles: &[crate::db::Turtle]) -> Vec<String> {
+    let mut seen = Vec::new();
+    for t in turtles {
+        if !seen.contains(&t.squad) {
+            seen.push(t.squad.clone());
+        }
+    }
+    seen
+}
@@ -0,0 +1,269 @@
+use std::sync::Mutex;
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::audit::AuditEvent;
+
+const DB_PATH: &str = "turtle_state.db";
+
+#[derive(Debug, Clone)]
+pub struct DataCenter {
+    pub name: String,
+    pub deploy_pct: u32,
+    pub status: String,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Turtle {
+    pub id: String,
+    pub squad: String,
+    pub ready: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Task {
+    pub priority: u32,
+    pub title: String,
+    pub status: String,
+}
+
+/// Embedded SQLite-backed state for the fleet/DC/task status the dashboard
+/// renders. Plays the same role a CI driver's state db plays: a single
+/// source of truth that commands mutate and views read from.
+///
+/// `Connection` is `Send` but not `Sync`, so a bare `&DbCtx` held across an
+/// `.await` (e.g. in `containers::refresh_deploy_status`) makes the
+/// enclosing future `!Send`. The `Mutex` costs nothing here - every method
+/// below locks it for one synchronous call and never across an await point -
+/// but it gives `DbCtx` a real `Sync` bound so shared references to it are
+/// safe to hand into `tokio::spawn`-driven async code.
+pub struct DbCtx {
+    conn: Mutex<Connection>,
+}
+
+impl DbCtx {
+    pub fn open() -> Result<Self> {
+        let conn = Connection::open(DB_PATH)?;
+        let ctx = DbCtx { conn: Mutex::new(conn) };
+        ctx.migrate()?;
+        Ok(ctx)
+    }
+
+    fn migrate(&self) -> Result<()> {
+        self.conn.lock().unwrap().execute_batch(
+            "CREATE TABLE IF NOT EXISTS data_centers (
+                name TEXT PRIMARY KEY,
+                deploy_pct INTEGER NOT NULL DEFAULT 0,
+                status TEXT NOT NULL DEFAULT 'not started',
+                last_error TEXT
+            );
+            CREATE TABLE IF NOT EXISTS turtles (
+                id TEXT PRIMARY KEY,
+                squad TEXT NOT NULL,
+                ready INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE TABLE IF NOT EXISTS tasks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                priority INTEGER NOT NULL,
+                title TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'open'
+            );
+            CREATE TABLE IF NOT EXISTS audit_events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                ts INTEGER NOT NULL,
+                node_id TEXT NOT NULL,
+                command TEXT NOT NULL,
+                risk_level TEXT NOT NULL,
+                auth_outcome TEXT NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                success INTEGER NOT NULL,
+                error TEXT
+            );
+            CREATE INDEX IF NOT EXISTS audit_events_ts ON audit_events (ts);",
+        )?;
+
+        self.seed_if_empty()
+    }
+
+    /// First-boot seed so a fresh `turtle_state.db` starts from the same
+    /// numbers the dashboard used to hardcode, rather than an empty fleet.
+    fn seed_if_empty(&self) -> Result<()> {
+        let dc_count: u32 = self
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM data_centers", [], |row| row.get(0))?;
+        if dc_count == 0 {
+            self.conn.lock().unwrap().execute(
+                "INSERT INTO data_centers (name, deploy_pct, status, last_error) VALUES
+                    ('DC1', 20, 'SSH connectivity issues', NULL),
+                    ('DC2', 0, 'Awaiting setup', NULL),
+                    ('DC3', 0, 'Observer not deployed', NULL)",
+                [],
+            )?;
+        }
+
+        let turtle_count: u32 = self
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM turtles", [], |row| row.get(0))?;
+        if turtle_count == 0 {
+            let squads = [("Operations", 11), ("Engineering", 5), ("Experimental", 5), ("Design", 4)];
+            for (squad, count) in squads {
+                for n in 0..count {
+                    self.conn.lock().unwrap().execute(
+                        "INSERT INTO turtles (id, squad, ready) VALUES (?1, ?2, 1)",
+                        params![format!("{}-{}", squad.to_lowercase(), n + 1), squad],
+                    )?;
+                }
+            }
+        }
+
+        let task_count: u32 = self
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get(0))?;
+        if task_count == 0 {
+            self.conn.lock().unwrap().execute(
+                "INSERT INTO tasks (priority, title, status) VALUES
+                    (1, 'Fix UDM Pro SSH access', 'open'),
+                    (2, 'Deploy turtle services', 'open'),
+                    (3, 'Enable 3-DC integration', 'open')",
+                [],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    pub fn data_centers(&self) -> Result<Vec<DataCenter>> {
+        let mut stmt = self
+            .conn
+            .lock()
+            .unwrap()
+            .prepare("SELECT name, deploy_pct, status, last_error FROM data_centers ORDER BY name")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(DataCenter {
+                name: row.get(0)?,
+                deploy_pct: row.get(1)?,
+                status: row.get(2)?,
+                last_error: row.get(3)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    pub fn turtles(&self) -> Result<Vec<Turtle>> {
+        let mut stmt = self.conn.lock().unwrap().prepare("SELECT id, squad, ready FROM turtles ORDER BY squad, id")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(Turtle {
+                id: row.get(0)?,
+                squad: row.get(1)?,
+                ready: row.get::<_, i64>(2)? != 0,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    pub fn tasks(&self) -> Result<Vec<Task>> {
+        let mut stmt = self.conn.lock().unwrap().prepare("SELECT priority, title, status FROM tasks ORDER BY priority")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(Task {
+                priority: row.get(0)?,
+                title: row.get(1)?,
+                status: row.get(2)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    pub fn set_dc_deploy_pct(&self, name: &str, pct: u32) -> Result<()> {
+        let updated = self.conn.lock().unwrap().execute(
+            "UPDATE data_centers SET deploy_pct = ?1 WHERE lower(name) = lower(?2)",
+            params![pct, name],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("no data center named '{}'", name);
+        }
+        Ok(())
+    }
+
+    pub fn set_dc_status(&self, name: &str, status: &str) -> Result<()> {
+        let updated = self.conn.lock().unwrap().execute(
+            "UPDATE data_centers SET status = ?1 WHERE lower(name) = lower(?2)",
+            params![status, name],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("no data center named '{}'", name);
+        }
+        Ok(())
+    }
+
+    pub fn add_task(&self, priority: u32, title: &str) -> Result<()> {
+        self.conn.lock().unwrap().execute(
+            "INSERT INTO tasks (priority, title, status) VALUES (?1, ?2, 'open')",
+            params![priority, title],
+        )?;
+        Ok(())
+    }
+
+    /// Append a batch of `audit::AuditEvent`s - called from the audit
+    /// subsystem's background writer, never directly from the REPL.
+    pub fn record_audit_events(&self, events: &[AuditEvent]) -> Result<()> {
+        for event in events {
+            self.conn.lock().unwrap().execute(
+                "INSERT INTO audit_events (ts, node_id, command, risk_level, auth_outcome, duration_ms, success, error)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    event.timestamp as i64,
+                    event.node_id,
+                    event.command,
+                    event.risk_level,
+                    event.auth_outcome,
+                    event.duration_ms as i64,
+                    event.success as i64,
+                    event.error,
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Most recent `limit` audit events, newest first - backs the REPL's
+    /// `history` command.
+    pub fn recent_audit_events(&self, limit: u32) -> Result<Vec<AuditEvent>> {
+        let mut stmt = self.conn.lock().unwrap().prepare(
+            "SELECT ts, node_id, command, risk_level, auth_outcome, duration_ms, success, error
+             FROM audit_events ORDER BY ts DESC LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![limit], |row| {
+            Ok(AuditEvent {
+                timestamp: row.get::<_, i64>(0)? as u64,
+                node_id: row.get(1)?,
+                command: row.get(2)?,
+                risk_level: row.get(3)?,
+                auth_outcome: row.get(4)?,
+                duration_ms: row.get::<_, i64>(5)? as u64,
+                success: row.get::<_, i64>(6)? != 0,
+                error: row.get(7)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+
+    /// Command counts grouped by risk level - the aggregated view the
+    /// `history` command shows alongside recent events.
+    pub fn audit_counts_by_risk(&self) -> Result<Vec<(String, u32)>> {
+        let mut stmt = self
+            .conn
+            .lock()
+            .unwrap()
+            .prepare("SELECT risk_level, COUNT(*) FROM audit_events GROUP BY risk_level ORDER BY risk_level")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get::<_, i64>(1)? as u32)))?;
+        Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+    }
+}
@@ -0,0 +1,191 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::safety::SystemState;
+
+const SNAPSHOT_DIR: &str = "turtle_diagnostics";
+
+/// A post-mortem read of one process: command line, parent, open files, and
+/// memory maps, in the spirit of a minidump/process-reader walk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessDiagnostic {
+    pub pid: u32,
+    pub command_line: String,
+    pub parent_pid: Option<u32>,
+    pub open_fds: Vec<String>,
+    pub memory_maps: Vec<String>,
+    pub thread_count: usize,
+}
+
+impl ProcessDiagnostic {
+    #[cfg(target_os = "linux")]
+    pub fn read(pid: u32) -> Result<Self> {
+        let base = PathBuf::from(format!("/proc/{}", pid));
+
+        let command_line = std::fs::read_to_string(base.join("cmdline"))
+            .unwrap_or_default()
+            .replace('\0', " ")
+            .trim()
+            .to_string();
+
+        let parent_pid = std::fs::read_to_string(base.join("status"))
+            .ok()
+            .and_then(|status| {
+                status
+                    .lines()
+                    .find(|l| l.starts_with("PPid:"))
+                    .and_then(|l| l.split_whitespace().nth(1))
+                    .and_then(|p| p.parse().ok())
+            });
+
+        let open_fds = std::fs::read_dir(base.join("fd"))
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .filter_map(|e| std::fs::read_link(e.path()).ok())
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let memory_maps = std::fs::read_to_string(base.join("maps"))
+            .map(|s| s.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        let thread_count = std::fs::read_dir(base.join("task"))
+            .map(|entries| entries.filter_map(|e| e.ok()).count())
+            .unwrap_or(0);
+
+        Ok(ProcessDiagnostic {
+            pid,
+            command_line,
+            parent_pid,
+            open_fds,
+            memory_maps,
+            thread_count,
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read(pid: u32) -> Result<Self> {
+        Ok(ProcessDiagnostic {
+            pid,
+            command_line: String::new(),
+            parent_pid: None,
+            open_fds: Vec::new(),
+            memory_maps: Vec::new(),
+            thread_count: 0,
+        })
+    }
+}
+
+/// A forensic artifact written whenever `verify_safe_execution` flags an
+/// anomaly, instead of transient console warnings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticSnapshot {
+    pub timestamp: u64,
+    pub reason: String,
+    pub processes: Vec<ProcessDiagnostic>,
+    pub pre_state: SystemState,
+    pub post_state: SystemState,
+}
+
+impl DiagnosticSnapshot {
+    pub fn capture(reason: &str, pids: &[u32], pre_state: &SystemState, post_state: &SystemState) -> Self {
+        let processes = pids
+            .iter()
+            .filter_map(|pid| ProcessDiagnostic::read(*pid).ok())
+            .collect();
+
+        DiagnosticSnapshot {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            reason: reason.to_string(),
+            processes,
+            pre_state: pre_state.clone(),
+            post_state: post_state.clone(),
+        }
+    }
+
+    /// Write the snapshot as timestamped JSON under `dir` and return the path.
+    pub fn write(&self, dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("snapshot-{}.json", self.timestamp));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
+
+pub fn default_dir() -> PathBuf {
+    PathBuf::from(SNAPSHOT_DIR)
+}
+
+/// Severity of a structured diagnostic pulled out of a tool's line-oriented
+/// output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+/// One structured finding from a stream of tool output - replaces an opaque
+/// blob of text with something a fleet observation can act on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub level: DiagnosticLevel,
+    pub message: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub span: Option<(u32, u32)>,
+}
+
+/// Recognizes `cargo`/`rustc --message-format=json` compiler-message
+/// records on a `crate::streaming` line stream and turns them into
+/// `Diagnostic`s. Lines that aren't JSON compiler messages (plain build
+/// output, cargo's own status lines) parse to `None` and pass through as
+/// unstructured output rather than erroring the whole stream.
+#[derive(Debug, Clone, Default)]
+pub struct CargoJsonDiagnosticParser;
+
+impl crate::streaming::ParseFromLine for CargoJsonDiagnosticParser {
+    type Output = Diagnostic;
+
+    fn parse_line(&self, line: &str) -> Option<Diagnostic> {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        if value.get("reason").and_then(|r| r.as_str()) != Some("compiler-message") {
+            return None;
+        }
+        let message = value.get("message")?;
+        let level = match message.get("level").and_then(|l| l.as_str())? {
+            "error" => DiagnosticLevel::Error,
+            "warning" => DiagnosticLevel::Warning,
+            "help" => DiagnosticLevel::Help,
+            _ => DiagnosticLevel::Note,
+        };
+        let text = message.get("message").and_then(|m| m.as_str())?.to_string();
+
+        let primary_span = message
+            .get("spans")
+            .and_then(|s| s.as_array())
+            .and_then(|spans| spans.iter().find(|s| s.get("is_primary").and_then(|p| p.as_bool()).unwrap_or(false)));
+
+        let file = primary_span
+            .and_then(|s| s.get("file_name"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        let line_no = primary_span
+            .and_then(|s| s.get("line_start"))
+            .and_then(|v| v.as_u64())
+            .map(|v| v as u32);
+        let span = primary_span.and_then(|s| {
+            let start = s.get("column_start").and_then(|v| v.as_u64())?;
+            let end = s.get("column_end").and_then(|v| v.as_u64())?;
+            Some((start as u32, end as u32))
+        });
+
+        Some(Diagnostic { level, message: text, file, line: line_no, span })
+    }
+}
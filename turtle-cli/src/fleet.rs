@@ -0,0 +1,168 @@
+use anyhow::{Context, Result};
+use bollard::container::{InspectContainerOptions, StatsOptions};
+use bollard::exec::{CreateExecOptions, StartExecResults};
+use futures_util::StreamExt;
+
+use crate::command_parser::ParsedCommand;
+use crate::containers::{self, DcEndpoint};
+use crate::shell::Shell;
+
+/// Every data center this turtle knows how to reach, as container-runtime
+/// endpoints - the same list `containers::deploy`/`refresh_deploy_status`
+/// already use, surfaced here so fleet-wide commands have one place to fan
+/// out over.
+pub fn endpoints() -> Vec<DcEndpoint> {
+    containers::default_endpoints()
+}
+
+/// Which endpoint has a running container named/id'd `id_or_name`? Errors if
+/// more than one does, since a caller picking the first match silently would
+/// risk running a command against the wrong DC.
+pub async fn find_endpoint(id_or_name: &str) -> Result<Option<DcEndpoint>> {
+    let mut owners = Vec::new();
+    for endpoint in endpoints() {
+        if containers::has_container(&endpoint, id_or_name).await.unwrap_or(false) {
+            owners.push(endpoint);
+        }
+    }
+
+    match owners.len() {
+        0 => Ok(None),
+        1 => Ok(owners.into_iter().next()),
+        _ => {
+            let dcs: Vec<String> = owners.iter().map(|e| e.dc.clone()).collect();
+            anyhow::bail!(
+                "container '{}' is ambiguous: running on {} endpoints ({})",
+                id_or_name,
+                owners.len(),
+                dcs.join(", ")
+            )
+        }
+    }
+}
+
+/// Run a `ParsedCommand`'s target as a shell line inside `container` on
+/// `endpoint`, the same way `execute_process_command` runs it locally via
+/// `Shell::program_and_args`, and return the combined stdout/stderr.
+pub async fn run_in_container(endpoint: &DcEndpoint, container: &str, command: &ParsedCommand) -> Result<String> {
+    let docker = containers::connect(endpoint)?;
+    let line = command.parameters.get("target").cloned().unwrap_or_default();
+    let shell = command.shell.clone().unwrap_or_else(Shell::default_for_platform);
+    let (program, args) = shell.program_and_args(&line);
+
+    let mut argv = vec![program];
+    argv.extend(args);
+
+    let exec = docker
+        .create_exec(
+            container,
+            CreateExecOptions {
+                cmd: Some(argv),
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .with_context(|| format!("creating exec on {} ({})", container, endpoint.dc))?;
+
+    match docker
+        .start_exec(&exec.id, None)
+        .await
+        .with_context(|| format!("starting exec on {} ({})", container, endpoint.dc))?
+    {
+        StartExecResults::Attached { mut output, .. } => {
+            let mut combined = String::new();
+            while let Some(chunk) = output.next().await {
+                combined.push_str(&chunk?.to_string());
+            }
+            Ok(combined)
+        }
+        StartExecResults::Detached => Ok(String::new()),
+    }
+}
+
+/// Start every endpoint's declared service containers concurrently, one
+/// `containers::deploy` call per DC, and report back per-DC so a single
+/// unreachable endpoint doesn't hide the ones that succeeded.
+pub async fn deploy_fleet() -> Vec<(String, Result<Vec<String>>)> {
+    let endpoints = endpoints();
+    let deploys = endpoints.iter().map(|endpoint| async move {
+        let result = containers::deploy(endpoint).await;
+        (endpoint.dc.clone(), result)
+    });
+    futures_util::future::join_all(deploys).await
+}
+
+/// A single container's observed status and resource usage, for fleet
+/// observation - "running"/"exited" plus the live CPU/memory numbers
+/// `docker stats` would show, rather than a one-shot presence check.
+#[derive(Debug, Clone)]
+pub struct ContainerObservation {
+    pub dc: String,
+    pub container: String,
+    pub state: String,
+    pub cpu_percent: f64,
+    pub memory_mb: u64,
+}
+
+/// Inspect `id_or_name` on every endpoint that's running it and report its
+/// state plus resource stats, so "observe fleet <target>" reflects reality
+/// instead of a placeholder string.
+pub async fn observe(id_or_name: &str) -> Result<Vec<ContainerObservation>> {
+    let mut observations = Vec::new();
+
+    for endpoint in endpoints() {
+        if !containers::has_container(&endpoint, id_or_name).await.unwrap_or(false) {
+            continue;
+        }
+
+        let docker = containers::connect(&endpoint)?;
+        let inspect = docker
+            .inspect_container(id_or_name, None::<InspectContainerOptions>)
+            .await
+            .with_context(|| format!("inspecting {} on {}", id_or_name, endpoint.dc))?;
+        let state = inspect
+            .state
+            .and_then(|s| s.status)
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let (cpu_percent, memory_mb) = docker
+            .stats(id_or_name, Some(StatsOptions { stream: false, one_shot: true }))
+            .next()
+            .await
+            .transpose()?
+            .map(|stats| {
+                let memory_mb = stats.memory_stats.usage.unwrap_or(0) / 1024 / 1024;
+                (cpu_percent_delta(&stats), memory_mb)
+            })
+            .unwrap_or((0.0, 0));
+
+        observations.push(ContainerObservation {
+            dc: endpoint.dc.clone(),
+            container: id_or_name.to_string(),
+            state,
+            cpu_percent,
+            memory_mb,
+        });
+    }
+
+    Ok(observations)
+}
+
+/// Docker's own CPU-percent formula: the container's share of total CPU time
+/// consumed since the previous sample, scaled by the number of online CPUs.
+fn cpu_percent_delta(stats: &bollard::container::Stats) -> f64 {
+    let cpu_delta = stats.cpu_stats.cpu_usage.total_usage as f64
+        - stats.precpu_stats.cpu_usage.total_usage as f64;
+    let system_delta = stats.cpu_stats.system_cpu_usage.unwrap_or(0) as f64
+        - stats.precpu_stats.system_cpu_usage.unwrap_or(0) as f64;
+    let online_cpus = stats.cpu_stats.online_cpus.unwrap_or(1) as f64;
+
+    if system_delta > 0.0 && cpu_delta > 0.0 {
+        (cpu_delta / system_delta) * online_cpus * 100.0
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,228 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::env;
+use wayland_client::globals::GlobalListContents;
+use wayland_client::protocol::wl_registry;
+use wayland_client::{backend::ObjectId, Connection, Dispatch, Proxy, QueueHandle};
+use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1::{
+    self, ZwlrForeignToplevelHandleV1,
+};
+use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_manager_v1::{
+    self, ZwlrForeignToplevelManagerV1,
+};
+
+use crate::command_parser::WindowGeometry;
+
+/// Query and reposition real on-screen windows by geometry - the runtime
+/// counterpart to `layout::tile`, which only computes target rectangles.
+/// Unlike `platform::PlatformBackend` (an OS chosen at compile time), the
+/// right implementation here depends on which display server is actually
+/// running, so `detect` picks one at runtime instead.
+#[async_trait]
+pub trait GeometryBackend {
+    async fn query_windows(&self) -> Result<Vec<WindowGeometry>>;
+    async fn move_resize(&self, window: &WindowGeometry) -> Result<()>;
+}
+
+/// Pick a backend for whichever display server is actually running:
+/// Wayland if `WAYLAND_DISPLAY` is set (checked first, since a Wayland
+/// session commonly also sets `DISPLAY` for XWayland compatibility and the
+/// wlr protocols are the more accurate source there), X11 if only `DISPLAY`
+/// is set, and a no-op backend if neither is - e.g. headless CI, or a
+/// compositor exposing no geometry protocol at all.
+pub fn detect() -> Box<dyn GeometryBackend + Send + Sync> {
+    if env::var("WAYLAND_DISPLAY").is_ok() {
+        Box::new(WaylandBackend)
+    } else if env::var("DISPLAY").is_ok() {
+        Box::new(X11Backend)
+    } else {
+        Box::new(NoneBackend)
+    }
+}
+
+/// Real window geometry via `xcb`, the same information `wmctrl -l -G`
+/// surfaces (see `platform::unix::windows_wmctrl`) but over the X11
+/// protocol directly, and with `move_resize` able to act on it.
+pub struct X11Backend;
+
+#[async_trait]
+impl GeometryBackend for X11Backend {
+    async fn query_windows(&self) -> Result<Vec<WindowGeometry>> {
+        let (conn, screen_num) = xcb::Connection::connect(None)?;
+        let setup = conn.get_setup();
+        let screen = setup
+            .roots()
+            .nth(screen_num as usize)
+            .ok_or_else(|| anyhow::anyhow!("no X11 screen {}", screen_num))?;
+
+        let client_list_atom = xcb::intern_atom(&conn, true, "_NET_CLIENT_LIST")
+            .get_reply()?
+            .atom();
+        let client_list = xcb::get_property(&conn, false, screen.root(), client_list_atom, xcb::ATOM_WINDOW, 0, 1024)
+            .get_reply()?;
+
+        let mut windows = Vec::new();
+        for &win in client_list.value::<u32>() {
+            let geom = xcb::get_geometry(&conn, win).get_reply()?;
+            windows.push(WindowGeometry {
+                id: format!("{:#x}", win),
+                x: geom.x() as i32,
+                y: geom.y() as i32,
+                width: geom.width() as u32,
+                height: geom.height() as u32,
+            });
+        }
+
+        Ok(windows)
+    }
+
+    async fn move_resize(&self, window: &WindowGeometry) -> Result<()> {
+        let win = u32::from_str_radix(window.id.trim_start_matches("0x"), 16)
+            .map_err(|_| anyhow::anyhow!("not an X11 window id: {}", window.id))?;
+        let (conn, _) = xcb::Connection::connect(None)?;
+        xcb::configure_window(
+            &conn,
+            win,
+            &[
+                (xcb::CONFIG_WINDOW_X as u16, window.x as u32),
+                (xcb::CONFIG_WINDOW_Y as u16, window.y as u32),
+                (xcb::CONFIG_WINDOW_WIDTH as u16, window.width),
+                (xcb::CONFIG_WINDOW_HEIGHT as u16, window.height),
+            ],
+        );
+        conn.flush();
+        Ok(())
+    }
+}
+
+/// `wlr-foreign-toplevel-management-v1` via `wayland-client`. The protocol
+/// only advertises title/app-id/state (maximized, minimized, activated,
+/// fullscreen) for other clients' toplevels, not position or size - there's
+/// no geometry to report, so `query_windows` returns one zero-sized entry
+/// per toplevel (id = app-id) rather than pretending to have real numbers.
+/// `wlr-layer-shell-v1` doesn't help either: it lets a client position
+/// surfaces *it creates itself* (bars, overlays), not move someone else's
+/// window.
+pub struct WaylandBackend;
+
+#[async_trait]
+impl GeometryBackend for WaylandBackend {
+    async fn query_windows(&self) -> Result<Vec<WindowGeometry>> {
+        let conn = wayland_client::Connection::connect_to_env()
+            .map_err(|e| anyhow::anyhow!("connecting to Wayland compositor: {}", e))?;
+        let (globals, mut queue) = wayland_client::globals::registry_queue_init::<ToplevelState>(&conn)
+            .map_err(|e| anyhow::anyhow!("enumerating Wayland globals: {}", e))?;
+
+        let Ok(manager) = globals.bind::<ZwlrForeignToplevelManagerV1, _, _>(&queue.handle(), 1..=3, ()) else {
+            // Compositor doesn't implement the protocol at all - nothing to
+            // query, not an error.
+            return Ok(Vec::new());
+        };
+        let _ = manager;
+
+        let mut state = ToplevelState::default();
+        // First roundtrip: the manager announces one `Event::Toplevel` per
+        // open window, handing us a handle for each. Those handles only get
+        // their `Event::AppId` (and `Event::Done`) on a *later* roundtrip, so
+        // one pass alone would always see an empty set of app-ids.
+        queue
+            .roundtrip(&mut state)
+            .map_err(|e| anyhow::anyhow!("Wayland roundtrip: {}", e))?;
+        queue
+            .roundtrip(&mut state)
+            .map_err(|e| anyhow::anyhow!("Wayland roundtrip: {}", e))?;
+
+        Ok(state
+            .app_ids
+            .into_values()
+            .filter(|app_id| !app_id.is_empty())
+            .map(|app_id| WindowGeometry { id: app_id, x: 0, y: 0, width: 0, height: 0 })
+            .collect())
+    }
+
+    async fn move_resize(&self, window: &WindowGeometry) -> Result<()> {
+        anyhow::bail!(
+            "Wayland has no protocol that lets a client reposition another client's window ({}); \
+             the compositor itself is the only thing that can tile '{}'",
+            window.id,
+            window.id
+        )
+    }
+}
+
+/// app-id per still-open toplevel, keyed by the handle's object id since
+/// `Event::AppId` (and `Event::Closed`) arrive on their own handle, separate
+/// from the `Event::Toplevel` that announced it.
+#[derive(Default)]
+struct ToplevelState {
+    app_ids: HashMap<ObjectId, String>,
+}
+
+// `registry_queue_init` needs `D: Dispatch<WlRegistry, GlobalListContents>` to
+// drive the global-list scan it does internally; the events themselves are
+// already consumed by that scan; `globals.bind` below has the copy we need.
+impl Dispatch<wl_registry::WlRegistry, GlobalListContents> for ToplevelState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &wl_registry::WlRegistry,
+        _event: wl_registry::Event,
+        _data: &GlobalListContents,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwlrForeignToplevelManagerV1, ()> for ToplevelState {
+    fn event(
+        state: &mut Self,
+        _proxy: &ZwlrForeignToplevelManagerV1,
+        event: zwlr_foreign_toplevel_manager_v1::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        if let zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } = event {
+            state.app_ids.insert(toplevel.id(), String::new());
+        }
+    }
+}
+
+impl Dispatch<ZwlrForeignToplevelHandleV1, ()> for ToplevelState {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwlrForeignToplevelHandleV1,
+        event: zwlr_foreign_toplevel_handle_v1::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        match event {
+            zwlr_foreign_toplevel_handle_v1::Event::AppId { app_id } => {
+                state.app_ids.insert(proxy.id(), app_id);
+            }
+            zwlr_foreign_toplevel_handle_v1::Event::Closed => {
+                state.app_ids.remove(&proxy.id());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Falls back here when neither `WAYLAND_DISPLAY` nor `DISPLAY` is set -
+/// headless CI, an SSH session with no forwarded display, or any compositor
+/// exposing no geometry protocol. Matches `PlatformBackend`'s
+/// empty-list-over-error philosophy for the same situations.
+pub struct NoneBackend;
+
+#[async_trait]
+impl GeometryBackend for NoneBackend {
+    async fn query_windows(&self) -> Result<Vec<WindowGeometry>> {
+        Ok(Vec::new())
+    }
+
+    async fn move_resize(&self, _window: &WindowGeometry) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use crate::command_parser::WindowGeometry;
+
+/// A tile is never shrunk below this, so a screen crowded with more windows
+/// than it can reasonably fit (or an oversized `gap`) can't produce a
+/// zero-or-negative geometry.
+const MIN_TILE_SIZE: u32 = 1;
+
+/// How `tile` arranges a set of tracked windows within the screen bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LayoutMode {
+    /// One master window on the left, the rest stacked in a column on the
+    /// right. `ratio` is the master's share of usable width, e.g. `0.6`.
+    MasterStack { ratio: f32 },
+    /// An evenly sized grid, `ceil(sqrt(n))` columns by as many rows as `n`
+    /// needs.
+    Grid,
+}
+
+/// Reposition `windows` to tile `screen`, leaving `gap` pixels between tiles
+/// and around the screen's edges. Only window count and index order drive
+/// the math - the x/y/width/height passed in are ignored - but each output
+/// tile keeps the `id` of the window at its index, so a caller can hand the
+/// result straight to `GeometryBackend::move_resize`.
+pub fn tile(windows: &[WindowGeometry], screen: WindowGeometry, gap: u32, mode: LayoutMode) -> Vec<WindowGeometry> {
+    let n = windows.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let rects = if n == 1 {
+        vec![inset(screen, gap)]
+    } else {
+        match mode {
+            LayoutMode::MasterStack { ratio } => master_stack(n, screen, gap, ratio),
+            LayoutMode::Grid => grid(n, screen, gap),
+        }
+    };
+
+    rects
+        .into_iter()
+        .zip(windows)
+        .map(|(rect, window)| WindowGeometry { id: window.id.clone(), ..rect })
+        .collect()
+}
+
+/// `screen` shrunk by `gap` on every side - the single-window case, and the
+/// master tile's height in `master_stack`.
+fn inset(screen: WindowGeometry, gap: u32) -> WindowGeometry {
+    WindowGeometry {
+        id: String::new(),
+        x: screen.x + gap as i32,
+        y: screen.y + gap as i32,
+        width: shrink(screen.width, 2 * gap),
+        height: shrink(screen.height, 2 * gap),
+    }
+}
+
+fn shrink(total: u32, by: u32) -> u32 {
+    total.saturating_sub(by).max(MIN_TILE_SIZE)
+}
+
+fn master_stack(n: usize, screen: WindowGeometry, gap: u32, ratio: f32) -> Vec<WindowGeometry> {
+    let usable_w = shrink(screen.width, 3 * gap);
+    let usable_h = shrink(screen.height, 2 * gap);
+    let master_width = shrink(((usable_w as f32) * ratio).round() as u32, 0).min(usable_w.saturating_sub(MIN_TILE_SIZE)).max(MIN_TILE_SIZE);
+    let stack_width = shrink(usable_w, master_width);
+
+    let stack_count = (n - 1) as u32;
+    let stack_gaps = stack_count.saturating_sub(1) * gap;
+    let stack_total_h = shrink(usable_h, stack_gaps);
+    let base_stack_h = (stack_total_h / stack_count).max(MIN_TILE_SIZE);
+    let remainder = stack_total_h.saturating_sub(base_stack_h * stack_count);
+
+    let mut tiles = Vec::with_capacity(n);
+    tiles.push(WindowGeometry {
+        id: String::new(),
+        x: screen.x + gap as i32,
+        y: screen.y + gap as i32,
+        width: master_width,
+        height: usable_h,
+    });
+
+    let stack_x = screen.x + 2 * gap as i32 + master_width as i32;
+    let mut y = screen.y + gap as i32;
+    for i in 0..stack_count {
+        let height = if i == stack_count - 1 { base_stack_h + remainder } else { base_stack_h };
+        tiles.push(WindowGeometry { id: String::new(), x: stack_x, y, width: stack_width, height });
+        y += height as i32 + gap as i32;
+    }
+
+    tiles
+}
+
+fn grid(n: usize, screen: WindowGeometry, gap: u32) -> Vec<WindowGeometry> {
+    let cols = (n as f64).sqrt().ceil() as usize;
+    let rows = (n + cols - 1) / cols;
+
+    let usable_w = shrink(screen.width, (cols as u32 + 1) * gap);
+    let usable_h = shrink(screen.height, (rows as u32 + 1) * gap);
+    let base_w = (usable_w / cols as u32).max(MIN_TILE_SIZE);
+    let base_h = (usable_h / rows as u32).max(MIN_TILE_SIZE);
+    let w_remainder = usable_w.saturating_sub(base_w * cols as u32);
+    let h_remainder = usable_h.saturating_sub(base_h * rows as u32);
+
+    (0..n)
+        .map(|i| {
+            let row = i / cols;
+            let col = i % cols;
+            let is_last = i == n - 1;
+            WindowGeometry {
+                id: String::new(),
+                x: screen.x + gap as i32 + col as i32 * (base_w as i32 + gap as i32),
+                y: screen.y + gap as i32 + row as i32 * (base_h as i32 + gap as i32),
+                width: if is_last { base_w + w_remainder } else { base_w },
+                height: if is_last { base_h + h_remainder } else { base_h },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen() -> WindowGeometry {
+        WindowGeometry { id: String::new(), x: 0, y: 0, width: 1920, height: 1080 }
+    }
+
+    fn windows(n: usize) -> Vec<WindowGeometry> {
+        (0..n)
+            .map(|i| WindowGeometry { id: format!("win{}", i), x: 0, y: 0, width: 0, height: 0 })
+            .collect()
+    }
+
+    #[test]
+    fn tile_empty_returns_empty() {
+        assert!(tile(&[], screen(), 10, LayoutMode::Grid).is_empty());
+    }
+
+    #[test]
+    fn tile_single_window_insets_whole_screen() {
+        let tiles = tile(&windows(1), screen(), 10, LayoutMode::Grid);
+        assert_eq!(tiles.len(), 1);
+        let t = &tiles[0];
+        assert_eq!(t.id, "win0");
+        assert_eq!(t.x, 10);
+        assert_eq!(t.y, 10);
+        assert_eq!(t.width, 1900);
+        assert_eq!(t.height, 1060);
+    }
+
+    #[test]
+    fn tile_preserves_window_ids_in_order() {
+        let tiles = tile(&windows(3), screen(), 10, LayoutMode::Grid);
+        assert_eq!(tiles.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), vec!["win0", "win1", "win2"]);
+    }
+
+    #[test]
+    fn master_stack_master_is_on_the_left_of_the_stack() {
+        let tiles = master_stack(3, screen(), 10, 0.6);
+        assert_eq!(tiles.len(), 3);
+        let master = &tiles[0];
+        assert!(tiles[1..].iter().all(|t| t.x > master.x + master.width as i32));
+    }
+
+    #[test]
+    fn master_stack_stack_tiles_fill_screen_height() {
+        let tiles = master_stack(3, screen(), 10, 0.6);
+        let last = tiles.last().unwrap();
+        // The last stack tile's bottom edge should land on the screen's
+        // bottom inset, with any rounding remainder folded into it.
+        assert_eq!(last.y + last.height as i32, screen().height as i32 - 10);
+    }
+
+    #[test]
+    fn master_stack_never_shrinks_below_min_tile_size() {
+        // Far more windows than the screen can reasonably fit.
+        let tiles = master_stack(50, screen(), 10, 0.6);
+        assert!(tiles.iter().all(|t| t.width >= MIN_TILE_SIZE && t.height >= MIN_TILE_SIZE));
+    }
+
+    #[test]
+    fn grid_four_windows_is_two_by_two() {
+        let tiles = grid(4, screen(), 10);
+        assert_eq!(tiles.len(), 4);
+        // Two distinct x positions (columns), two distinct y positions (rows).
+        let xs: std::collections::HashSet<i32> = tiles.iter().map(|t| t.x).collect();
+        let ys: std::collections::HashSet<i32> = tiles.iter().map(|t| t.y).collect();
+        assert_eq!(xs.len(), 2);
+        assert_eq!(ys.len(), 2);
+    }
+
+    #[test]
+    fn grid_never_shrinks_below_min_tile_size() {
+        let tiles = grid(50, screen(), 10);
+        assert!(tiles.iter().all(|t| t.width >= MIN_TILE_SIZE && t.height >= MIN_TILE_SIZE));
+    }
+}
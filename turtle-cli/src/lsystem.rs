@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::command_parser::WindowGeometry;
+
+/// Hard cap on the expanded axiom's length. Rule bodies can be longer than
+/// the character they replace, so `n` iterations can blow the string up
+/// exponentially (e.g. `F -> FF` doubles it every round) - this stops
+/// `expand` before that exhausts memory instead of after.
+pub const MAX_EXPANDED_LEN: usize = 1_000_000;
+
+/// One line the turtle drew while walking the expanded string, in the same
+/// coordinate space `layout::tile` and `WindowGeometry` use.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineSegment {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TurtleState {
+    x: f64,
+    y: f64,
+    heading_degrees: f64,
+}
+
+/// Replace every character in `axiom` with its rule body (or itself, if
+/// `rules` has no entry for it), `n` times. Bails out once the string would
+/// exceed `max_len` rather than let a recursive grammar like `F -> FF`
+/// exhaust memory.
+pub fn expand(axiom: &str, rules: &HashMap<char, String>, n: u32, max_len: usize) -> Result<String> {
+    let mut current = axiom.to_string();
+    for _ in 0..n {
+        let mut next = String::with_capacity(current.len());
+        for c in current.chars() {
+            match rules.get(&c) {
+                Some(body) => next.push_str(body),
+                None => next.push(c),
+            }
+            if next.len() > max_len {
+                anyhow::bail!(
+                    "L-system expansion exceeded the {} character cap (axiom {:?}, {} rule(s))",
+                    max_len,
+                    axiom,
+                    rules.len()
+                );
+            }
+        }
+        current = next;
+    }
+    Ok(current)
+}
+
+/// Walk `expanded` with turtle state `(x, y, heading)`, emitting a line
+/// segment for every forward step. Any alphabetic character moves forward
+/// by `step` - the classic grammars use `F` for a drawn line and `f`/`X`/`Y`
+/// etc. as structural no-op letters, but an interpreter that only drew `F`
+/// would silently ignore those conventions, so every letter draws.
+/// `+`/`-` turn by `angle_degrees`, and `[`/`]` push/pop the turtle state
+/// for branching - a stray `]` with nothing pushed is ignored rather than
+/// treated as an error, since an unbalanced grammar is a drawing quirk, not
+/// a reason to abort the render.
+pub fn interpret(expanded: &str, step: f64, angle_degrees: f64) -> (Vec<LineSegment>, WindowGeometry) {
+    let mut state = TurtleState { x: 0.0, y: 0.0, heading_degrees: 0.0 };
+    let mut stack: Vec<TurtleState> = Vec::new();
+    let mut segments = Vec::new();
+
+    let mut min_x = state.x;
+    let mut max_x = state.x;
+    let mut min_y = state.y;
+    let mut max_y = state.y;
+
+    for c in expanded.chars() {
+        match c {
+            '+' => state.heading_degrees += angle_degrees,
+            '-' => state.heading_degrees -= angle_degrees,
+            '[' => stack.push(state),
+            ']' => {
+                if let Some(popped) = stack.pop() {
+                    state = popped;
+                }
+            }
+            c if c.is_alphabetic() => {
+                let radians = state.heading_degrees.to_radians();
+                let next_x = state.x + step * radians.cos();
+                let next_y = state.y + step * radians.sin();
+                segments.push(LineSegment { x1: state.x, y1: state.y, x2: next_x, y2: next_y });
+
+                state.x = next_x;
+                state.y = next_y;
+                min_x = min_x.min(next_x);
+                max_x = max_x.max(next_x);
+                min_y = min_y.min(next_y);
+                max_y = max_y.max(next_y);
+            }
+            _ => {}
+        }
+    }
+
+    let bounds = WindowGeometry {
+        id: String::new(),
+        x: min_x.floor() as i32,
+        y: min_y.floor() as i32,
+        width: ((max_x - min_x).ceil() as u32).max(1),
+        height: ((max_y - min_y).ceil() as u32).max(1),
+    };
+
+    (segments, bounds)
+}
+
+/// `expand` then `interpret` in one call - what `execute_lsystem_render`
+/// actually drives.
+pub fn render(
+    axiom: &str,
+    rules: &HashMap<char, String>,
+    n: u32,
+    step: f64,
+    angle_degrees: f64,
+    max_len: usize,
+) -> Result<(Vec<LineSegment>, WindowGeometry)> {
+    let expanded = expand(axiom, rules, n, max_len)?;
+    Ok(interpret(&expanded, step, angle_degrees))
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::{generate, Shell};
 use std::io::{self, Write};
 use chrono::Timelike;
 use tokio::io::{AsyncBufReadExt, BufReader};
@@ -12,8 +13,27 @@ pub mod claude;
 pub mod safety;
 pub mod command_parser;
 pub mod cnl_config;
+pub mod watch;
+pub mod db;
+pub mod containers;
+pub mod supervisor;
+pub mod notifier;
+pub mod platform;
+pub mod diagnostics;
+pub mod simulation;
+pub mod shell;
+pub mod streaming;
+pub mod fleet;
+pub mod layout;
+pub mod geometry_backend;
+pub mod session;
+pub mod lsystem;
+pub mod workers;
+pub mod audit;
+pub mod mesh;
+pub mod coordinator;
+pub mod scheduler;
 
-use dashboard::Dashboard;
 use types::*;
 // use safety::{CoreInteractionPrinciple, SafetyContext, RiskLevel};
 use command_parser::CNLCommandParser;
@@ -41,6 +61,25 @@ enum Commands {
     Infra,
     /// Show full dashboard
     Dashboard,
+    /// Watch a pathset and re-run an action on change
+    Watch {
+        /// Paths to watch (defaults to the turtle infra directories)
+        #[arg(long)]
+        path: Vec<String>,
+        /// Re-run an arbitrary shell command instead of a dashboard
+        #[arg(long)]
+        command: Option<String>,
+        /// Watch infra status instead of the compact dashboard
+        #[arg(long)]
+        infra: bool,
+        /// What to do when an event arrives mid-run: queue, ignore, restart, signal
+        #[arg(long, default_value = "queue")]
+        on_busy_update: String,
+    },
+    /// Start the declared service containers for a data center
+    Deploy { dc: String },
+    /// Generate shell completion scripts
+    Completions { shell: Shell },
 }
 
 #[derive(Subcommand)]
@@ -66,32 +105,61 @@ enum WorkCommands {
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    // Every fleet-coordination action below - dashboards, state transitions,
+    // fleet dispatch - goes through this one handler loop instead of each
+    // call site constructing a `Dashboard` or mutating fleet state directly,
+    // so concurrent operations are ordered and HIGH-risk ones are checked
+    // against `top_turtle_authority_required` in exactly one place.
+    let safety = cnl_config::CNLConfigLoader::load_config()
+        .map(|c| c.safety_and_authority)
+        .unwrap_or_else(|_| cnl_config::CNLConfigLoader::default_from_cnl("").safety_and_authority);
+    let coordinator = coordinator::spawn(safety);
+
+    // One-shot CLI invocations aren't an interactive Top Turtle session, so
+    // they're not granted Top Turtle authority - only the REPL is.
+    let top_turtle = false;
+
     match cli.command {
         Some(Commands::Work { work_command }) => {
-            handle_work_commands(work_command).await?;
+            handle_work_commands(&coordinator, top_turtle, work_command).await?;
         }
         Some(Commands::Status) => {
-            show_quick_status().await?;
+            show_quick_status(&coordinator, top_turtle).await?;
         }
         Some(Commands::Infra) => {
-            show_infrastructure_status().await?;
+            show_infrastructure_status(&coordinator, top_turtle).await?;
         }
         Some(Commands::Dashboard) => {
-            show_full_dashboard().await?;
+            show_full_dashboard(&coordinator, top_turtle).await?;
+        }
+        Some(Commands::Watch { path, command, infra, on_busy_update }) => {
+            run_watch(path, command, infra, on_busy_update).await?;
+        }
+        Some(Commands::Deploy { dc }) => {
+            deploy_dc(&coordinator, top_turtle, &dc).await?;
+        }
+        Some(Commands::Completions { shell }) => {
+            let mut cmd = Cli::command();
+            let name = cmd.get_name().to_string();
+            generate(shell, &mut cmd, name, &mut io::stdout());
         }
         None => {
             // Default behavior - conversational REPL mode
-            repl_mode().await?;
+            repl_mode(&coordinator).await?;
         }
     }
 
     Ok(())
 }
 
-async fn handle_work_commands(work_command: Option<WorkCommands>) -> Result<()> {
+async fn handle_work_commands(
+    coordinator: &coordinator::CoordinatorHandle,
+    top_turtle: bool,
+    work_command: Option<WorkCommands>,
+) -> Result<()> {
     match work_command {
         Some(WorkCommands::Focus { duration }) => {
-            work::focus_mode(duration).await?;
+            coordinator.focus_mode(duration, top_turtle).await?;
         }
         Some(WorkCommands::Calendar) => {
             work::show_calendar().await?;
@@ -112,40 +180,125 @@ async fn handle_work_commands(work_command: Option<WorkCommands>) -> Result<()>
             work::end_of_day().await?;
         }
         None => {
-            show_work_dashboard().await?;
+            show_work_dashboard(coordinator, top_turtle).await?;
         }
     }
     Ok(())
 }
 
-async fn show_quick_status() -> Result<()> {
-    let mut dashboard = Dashboard::new().await?;
-    dashboard.show_compact().await?;
+async fn show_quick_status(coordinator: &coordinator::CoordinatorHandle, top_turtle: bool) -> Result<()> {
+    coordinator.show_dashboard(coordinator::DashboardMode::Compact, top_turtle).await?;
+    Ok(())
+}
+
+async fn show_work_dashboard(coordinator: &coordinator::CoordinatorHandle, top_turtle: bool) -> Result<()> {
+    coordinator.show_dashboard(coordinator::DashboardMode::WorkFocused, top_turtle).await?;
     Ok(())
 }
 
-async fn show_work_dashboard() -> Result<()> {
-    let mut dashboard = Dashboard::new().await?;
-    dashboard.show_work_focused().await?;
+async fn show_full_dashboard(coordinator: &coordinator::CoordinatorHandle, top_turtle: bool) -> Result<()> {
+    coordinator.show_dashboard(coordinator::DashboardMode::Expanded, top_turtle).await?;
     Ok(())
 }
 
-async fn show_full_dashboard() -> Result<()> {
-    let mut dashboard = Dashboard::new().await?;
-    dashboard.show_expanded().await?;
+async fn show_infrastructure_status(coordinator: &coordinator::CoordinatorHandle, top_turtle: bool) -> Result<()> {
+    refresh_container_status().await;
+    coordinator.show_dashboard(coordinator::DashboardMode::InfrastructureFocused, top_turtle).await?;
     Ok(())
 }
 
-async fn show_infrastructure_status() -> Result<()> {
-    let mut dashboard = Dashboard::new().await?;
-    dashboard.show_infrastructure_focused().await?;
+/// Reconcile each DC's deploy percentage against observed container state.
+/// Endpoints that can't be reached (no daemon running locally) are left
+/// recording their last known status rather than failing the dashboard.
+async fn refresh_container_status() {
+    let db = match db::DbCtx::open() {
+        Ok(db) => db,
+        Err(_) => return,
+    };
+
+    let notifier = match cnl_config::CNLConfigLoader::load_config() {
+        Ok(config) => notifier::Notifier::new(notifier::NotifierConfig::from_cnl(&config)),
+        Err(_) => notifier::Notifier::new(notifier::NotifierConfig::default()),
+    };
+
+    for endpoint in containers::default_endpoints() {
+        let previous = db
+            .data_centers()
+            .ok()
+            .and_then(|dcs| dcs.into_iter().find(|dc| dc.name == endpoint.dc));
+        let previous_pct = previous.as_ref().map(|dc| dc.deploy_pct).unwrap_or(0);
+        let was_unreachable = previous.as_ref().is_some_and(|dc| dc.status.starts_with("unreachable:"));
+
+        if let Ok(new_pct) = containers::refresh_deploy_status(&endpoint, &db).await {
+            if let Some(threshold) = notifier.crossed_threshold(previous_pct, new_pct) {
+                let _ = notifier
+                    .notify(notifier::Notification::DcThresholdCrossed { dc: endpoint.dc.clone(), pct: threshold })
+                    .await;
+            }
+
+            // refresh_deploy_status just set a reachable status, so if the
+            // prior poll recorded it unreachable, SSH/connectivity just came
+            // back - the only trigger for SshRecovered this subsystem can see.
+            if was_unreachable {
+                let _ = notifier.notify(notifier::Notification::SshRecovered { dc: endpoint.dc.clone() }).await;
+            }
+        }
+    }
+}
+
+async fn deploy_dc(coordinator: &coordinator::CoordinatorHandle, top_turtle: bool, dc: &str) -> Result<()> {
+    println!("🚀 Deploying declared services for {}...", dc);
+    match coordinator.dispatch_fleet(dc.to_string(), top_turtle).await {
+        Ok(summary) => println!("✅ {}", summary),
+        Err(e) => println!("❌ Deployment failed: {}", e),
+    }
     Ok(())
 }
 
-async fn repl_mode() -> Result<()> {
+async fn run_watch(
+    paths: Vec<String>,
+    command: Option<String>,
+    infra: bool,
+    on_busy_update: String,
+) -> Result<()> {
+    use watch::{OnBusyUpdate, WatchAction, WatchConfig};
+
+    let mut config = WatchConfig::default();
+
+    if !paths.is_empty() {
+        config.paths = paths.into_iter().map(std::path::PathBuf::from).collect();
+    }
+
+    config.action = if let Some(cmd) = command {
+        WatchAction::Command(cmd)
+    } else if infra {
+        WatchAction::InfraStatus
+    } else {
+        WatchAction::DashboardRefresh
+    };
+
+    config.on_busy_update = match on_busy_update.to_lowercase().as_str() {
+        "do-nothing" | "donothing" | "ignore" => OnBusyUpdate::DoNothing,
+        "restart" => OnBusyUpdate::Restart,
+        "signal" => OnBusyUpdate::Signal(15), // SIGTERM
+        _ => OnBusyUpdate::Queue,
+    };
+
+    watch::run(config).await
+}
+
+async fn repl_mode(coordinator: &coordinator::CoordinatorHandle) -> Result<()> {
     // Initialize enhanced command parser with Core Interaction Principle
     let mut command_parser = CNLCommandParser::new();
-    
+
+    // The interactive REPL is the one Top Turtle session - its dashboard and
+    // state-transition commands carry Top Turtle authority; one-shot CLI
+    // invocations of the same coordinator (see `main`) don't.
+    let top_turtle = true;
+
+    let worker_manager = spawn_background_workers(command_parser.supervisor());
+    let scheduler = spawn_scheduler(coordinator.clone()).await;
+
     // Detect Claude availability
     let claude_client = claude::Claude::new();
     let has_claude = claude_client.is_some();
@@ -168,15 +321,15 @@ async fn repl_mode() -> Result<()> {
     match startup_context {
         StartupContext::WorkTime => {
             println!("🎯 Good morning! Starting work mode...");
-            show_work_dashboard().await?;
+            show_work_dashboard(coordinator, top_turtle).await?;
         }
         StartupContext::InfraIssues => {
             println!("⚠️ Infrastructure issues detected. Showing infra status...");
-            show_infrastructure_status().await?;
+            show_infrastructure_status(coordinator, top_turtle).await?;
         }
         StartupContext::General => {
             println!("📊 Starting with status overview...");
-            show_quick_status().await?;
+            show_quick_status(coordinator, top_turtle).await?;
         }
     }
     
@@ -216,6 +369,27 @@ async fn repl_mode() -> Result<()> {
                 println!("🐢 See you later!");
                 break;
             }
+            "workers" => {
+                print_worker_status(&worker_manager);
+            }
+            "history" => {
+                print_audit_history();
+            }
+            input if input.to_lowercase().starts_with("worker ") => {
+                handle_worker_command(&worker_manager, input).await;
+            }
+            input if input.to_lowercase().starts_with("mesh ") => {
+                handle_mesh_command(input).await;
+            }
+            "schedule" => {
+                print_schedule(&scheduler).await;
+            }
+            input if input.to_lowercase().starts_with("schedule cancel ") => {
+                handle_schedule_cancel(&scheduler, input).await;
+            }
+            input if input.to_lowercase().starts_with("focus ") => {
+                handle_focus_command(coordinator, &scheduler, input).await;
+            }
             input => {
                 // Parse command using CNL patterns with Core Interaction Principle
                 match command_parser.parse_command(input).await {
@@ -229,9 +403,9 @@ async fn repl_mode() -> Result<()> {
                         println!("🤔 Could not parse command: {}", e);
                         // Fallback to Claude or smart input handling
                         if let Some(ref claude) = claude_client {
-                            handle_claude_input(input, claude).await?;
+                            handle_claude_input(input, claude, coordinator).await?;
                         } else {
-                            handle_smart_input(input).await?;
+                            handle_smart_input(input, coordinator).await?;
                         }
                     }
                 }
@@ -262,68 +436,335 @@ async fn detect_startup_context() -> Result<StartupContext> {
 }
 
 fn is_exit_command(input: &str) -> bool {
-    matches!(input.to_lowercase().as_str(), 
+    matches!(input.to_lowercase().as_str(),
         "exit" | "quit" | "bye" | "goodbye" | "done")
 }
 
-async fn handle_smart_input(input: &str) -> Result<()> {
+/// Stand up the fleet's self-maintenance loops - mesh healing, fleet
+/// discovery, health checks - so the intervals `MeshResilienceConfig` and
+/// `FleetCommunicationConfig` declare actually drive something instead of
+/// sitting unread in the CNL config. Falls back to the CNL defaults if
+/// `TURTLE_FLEET_CONFIG.cnl` isn't present, same as `refresh_container_status`.
+fn spawn_background_workers(supervisor: std::sync::Arc<tokio::sync::Mutex<supervisor::Supervisor>>) -> workers::WorkerManager {
+    let config = cnl_config::CNLConfigLoader::load_config()
+        .unwrap_or_else(|_| cnl_config::CNLConfigLoader::default_from_cnl(""));
+
+    let throttle = std::time::Duration::from_secs(1);
+    let mut manager = workers::WorkerManager::new();
+
+    manager.spawn(
+        Box::new(workers::SupervisorReapWorker::new(supervisor, std::time::Duration::from_secs(5))),
+        throttle,
+    );
+
+    manager.spawn(
+        Box::new(workers::MeshHealingWorker::new(
+            std::time::Duration::from_secs(config.mesh_resilience.mesh_healing_interval),
+            config.mesh_resilience.automatic_recovery,
+        )),
+        throttle,
+    );
+    manager.spawn(
+        Box::new(workers::FleetDiscoveryWorker::new(std::time::Duration::from_secs(
+            config.fleet_communication.discovery_interval,
+        ))),
+        throttle,
+    );
+    manager.spawn(
+        Box::new(workers::HealthCheckWorker::new(std::time::Duration::from_secs(
+            config.fleet_communication.health_check_interval,
+        ))),
+        throttle,
+    );
+
+    manager
+}
+
+/// Stand up the scheduler and seed it with the daily work/general mode flips
+/// and end-of-day trigger `StartupBehaviorConfig` declares, so those hours
+/// actually do something instead of only being read at REPL startup.
+async fn spawn_scheduler(coordinator: coordinator::CoordinatorHandle) -> scheduler::SchedulerHandle {
+    let config = cnl_config::CNLConfigLoader::load_config()
+        .unwrap_or_else(|_| cnl_config::CNLConfigLoader::default_from_cnl(""));
+
+    let scheduler = scheduler::spawn(coordinator);
+    if let Err(e) = scheduler::seed_startup_schedule(&scheduler, &config.startup_behavior).await {
+        println!("⚠️ Could not seed startup schedule: {}", e);
+    }
+    scheduler
+}
+
+/// The `focus <duration>` REPL command: starts focus mode via the
+/// coordinator same as bare `focus`, but also schedules an `EndFocusMode`
+/// entry so the session's duration actually elapses into something instead
+/// of being discarded.
+async fn handle_focus_command(coordinator: &coordinator::CoordinatorHandle, scheduler: &scheduler::SchedulerHandle, input: &str) {
+    let spec = input.splitn(2, char::is_whitespace).nth(1).unwrap_or("").trim();
+
+    let duration = match scheduler::parse_duration(spec) {
+        Ok(duration) => duration,
+        Err(e) => {
+            println!("🤔 {}", e);
+            return;
+        }
+    };
+
+    if let Err(e) = coordinator.focus_mode(Some(spec.to_string()), true).await {
+        println!("❌ {}", e);
+        return;
+    }
+
+    match scheduler
+        .schedule_once(format!("focus session ({})", spec), duration, scheduler::ScheduledAction::EndFocusMode)
+        .await
+    {
+        Ok(id) => println!("⏱️ Scheduled entry #{} to end this focus session in {}", id, spec),
+        Err(e) => println!("⚠️ Could not schedule focus-session end: {}", e),
+    }
+}
+
+/// The `schedule` REPL command: lists every entry the scheduler is holding.
+async fn print_schedule(scheduler: &scheduler::SchedulerHandle) {
+    match scheduler.list().await {
+        Ok(entries) if entries.is_empty() => println!("🗓️ No scheduled entries"),
+        Ok(entries) => {
+            println!("🗓️ Scheduled entries:");
+            for (id, label, remaining) in entries {
+                println!("   #{} {} - fires in {}", id, label, scheduler::format_duration(remaining));
+            }
+        }
+        Err(e) => println!("❌ Failed to list scheduled entries: {}", e),
+    }
+}
+
+/// The `schedule cancel <id>` REPL command.
+async fn handle_schedule_cancel(scheduler: &scheduler::SchedulerHandle, input: &str) {
+    let Some(id_str) = input.split_whitespace().nth(2) else {
+        println!("🤔 Usage: schedule cancel <id>");
+        return;
+    };
+    let Ok(id) = id_str.parse::<scheduler::EntryId>() else {
+        println!("🤔 '{}' isn't a valid schedule id", id_str);
+        return;
+    };
+
+    match scheduler.cancel(id).await {
+        Ok(true) => println!("✅ Cancelled scheduled entry #{}", id),
+        Ok(false) => println!("🤔 No scheduled entry #{}", id),
+        Err(e) => println!("❌ {}", e),
+    }
+}
+
+fn print_worker_status(manager: &workers::WorkerManager) {
+    println!("🛠️ Background workers:");
+    for (name, status, run_state) in manager.status() {
+        println!("   {} [{:?}] - {}", name, run_state, status);
+    }
+}
+
+/// The `history` REPL command: recent audit events plus a per-risk-level
+/// count, so `resource_usage_logging` has somewhere an operator can
+/// actually look.
+fn print_audit_history() {
+    let db = match db::DbCtx::open() {
+        Ok(db) => db,
+        Err(e) => {
+            println!("❌ Could not open audit database: {}", e);
+            return;
+        }
+    };
+
+    println!("🗒️ Recent fleet commands:");
+    match db.recent_audit_events(20) {
+        Ok(events) if events.is_empty() => println!("   (no audit events recorded yet)"),
+        Ok(events) => {
+            for event in events {
+                let outcome = if event.success { "ok" } else { "error" };
+                let error_suffix = event.error.map(|e| format!(" - {}", e)).unwrap_or_default();
+                println!(
+                    "   [{}] {} ({}, {}) {}ms {}{}",
+                    event.timestamp, event.command, event.risk_level, event.auth_outcome, event.duration_ms, outcome, error_suffix
+                );
+            }
+        }
+        Err(e) => println!("❌ Failed to read audit history: {}", e),
+    }
+
+    match db.audit_counts_by_risk() {
+        Ok(counts) if !counts.is_empty() => {
+            println!("📊 Command counts by risk level:");
+            for (risk, count) in counts {
+                println!("   {}: {}", risk, count);
+            }
+        }
+        Ok(_) => {}
+        Err(e) => println!("❌ Failed to aggregate audit history: {}", e),
+    }
+}
+
+async fn handle_worker_command(manager: &workers::WorkerManager, input: &str) {
+    let mut parts = input.split_whitespace().skip(1);
+    let (Some(name), Some(action)) = (parts.next(), parts.next()) else {
+        println!("🤔 Usage: worker <name> pause|resume|cancel");
+        return;
+    };
+
+    let msg = match action.to_lowercase().as_str() {
+        "pause" => workers::ControlMsg::Pause,
+        "resume" | "start" => workers::ControlMsg::Start,
+        "cancel" | "stop" => workers::ControlMsg::Cancel,
+        other => {
+            println!("🤔 Unknown worker action: '{}' (expected pause|resume|cancel)", other);
+            return;
+        }
+    };
+
+    match manager.control(name, msg).await {
+        Ok(()) => println!("✅ worker {} {}", name, action),
+        Err(e) => println!("❌ {}", e),
+    }
+}
+
+/// The `mesh <dc> <method>` REPL command: dial that DC's coordination port
+/// through a `mesh::MeshClient` (WebSocket primary, HTTP backup per
+/// `MeshResilienceConfig`), then issue `method` as a request and print
+/// whatever comes back. This is the thin call-site the dashboard/workers
+/// are meant to go through once they need to talk to a peer's mesh rather
+/// than just SSH/Docker into it.
+async fn handle_mesh_command(input: &str) {
+    let mut parts = input.split_whitespace().skip(1);
+    let (Some(dc_name), Some(method)) = (parts.next(), parts.next()) else {
+        println!("🤔 Usage: mesh <dc> <method>");
+        return;
+    };
+
+    let Some(endpoint) = fleet::endpoints().into_iter().find(|e| e.dc.eq_ignore_ascii_case(dc_name)) else {
+        println!("❌ No data center named '{}'", dc_name);
+        return;
+    };
+
+    let config = cnl_config::CNLConfigLoader::load_config()
+        .unwrap_or_else(|_| cnl_config::CNLConfigLoader::default_from_cnl(""));
+    let host = mesh_host(&endpoint.uri);
+    let peer_addr = format!("{}:{}", host, config.fleet_communication.mesh_ports.coordination_port);
+
+    let local_capabilities = [mesh::Capability::Coordination, mesh::Capability::WebsocketPrimary, mesh::Capability::HttpBackup]
+        .into_iter()
+        .collect();
+
+    let client = match mesh::MeshClient::connect(
+        &peer_addr,
+        local_capabilities,
+        std::time::Duration::from_secs(config.mesh_resilience.node_failure_timeout),
+        config.mesh_resilience.connection_retry_count,
+    )
+    .await
+    {
+        Ok(client) => client,
+        Err(e) => {
+            println!("❌ Could not reach {} mesh at {}: {}", endpoint.dc, peer_addr, e);
+            return;
+        }
+    };
+
+    match client.call(method, serde_json::json!({})).await {
+        Ok(reply) => println!("🕸️ {} ({}) -> {}", endpoint.dc, peer_addr, reply),
+        Err(e) => println!("❌ {} mesh call failed: {}", endpoint.dc, e),
+    }
+}
+
+/// `DcEndpoint.uri` is a container-runtime URI (`unix:///...` or
+/// `tcp://host:port`), not a mesh address - pull out just the host so the
+/// mesh client can pair it with `MeshPorts.coordination_port` instead of the
+/// Docker/Podman port embedded in `uri`.
+fn mesh_host(uri: &str) -> String {
+    if let Some(rest) = uri.strip_prefix("tcp://") {
+        rest.split(':').next().unwrap_or(rest).to_string()
+    } else {
+        "localhost".to_string()
+    }
+}
+
+async fn handle_smart_input(input: &str, coordinator: &coordinator::CoordinatorHandle) -> Result<()> {
     let lower_input = input.to_lowercase();
-    
+    let top_turtle = true; // handle_smart_input only ever runs inside the REPL's Top Turtle session
+
     // Smart pattern matching for natural language
     if lower_input.contains("status") || lower_input.contains("how") || lower_input.contains("what's") {
-        show_quick_status().await?;
+        show_quick_status(coordinator, top_turtle).await?;
     } else if lower_input.contains("work") || lower_input.contains("task") || lower_input.contains("todo") {
-        show_work_dashboard().await?;
+        show_work_dashboard(coordinator, top_turtle).await?;
     } else if lower_input.contains("dashboard") || lower_input.contains("full") || lower_input.contains("everything") {
-        show_full_dashboard().await?;
+        show_full_dashboard(coordinator, top_turtle).await?;
     } else if lower_input.contains("infra") || lower_input.contains("server") || lower_input.contains("deploy") {
-        show_infrastructure_status().await?;
+        show_infrastructure_status(coordinator, top_turtle).await?;
     } else if lower_input.contains("focus") || lower_input.contains("concentrate") {
-        work::focus_mode(None).await?;
+        coordinator.focus_mode(None, top_turtle).await?;
     } else if lower_input.contains("calendar") || lower_input.contains("schedule") || lower_input.contains("meeting") {
         work::show_calendar().await?;
     } else if lower_input.contains("transition") || lower_input.contains("change") || lower_input.contains("switch") {
-        turtle_mode_transition().await?;
+        turtle_mode_transition(coordinator, top_turtle).await?;
     } else if lower_input.contains("help") {
         show_smart_help();
     } else {
         // Intelligent response for unrecognized input
         println!("🤔 I understand you want: '{}'", input);
         println!("💡 Let me suggest what might help:");
-        
+
         if lower_input.len() < 4 {
-            show_quick_status().await?;
+            show_quick_status(coordinator, top_turtle).await?;
         } else if lower_input.contains("show") || lower_input.contains("see") {
-            show_work_dashboard().await?;
+            show_work_dashboard(coordinator, top_turtle).await?;
         } else {
             println!("   - Try: 'status', 'work', 'dashboard', or 'help'");
             println!("   - Or just describe what you want to do");
         }
     }
-    
+
     Ok(())
 }
 
-async fn handle_claude_input(input: &str, claude: &claude::Claude) -> Result<()> {
+async fn handle_claude_input(input: &str, claude: &claude::Claude, coordinator: &coordinator::CoordinatorHandle) -> Result<()> {
     // Try Claude first, fallback to built-in commands if Claude fails
     let context = get_current_context().await;
-    
-    match claude.chat(input, &context).await {
-        Ok(response) => {
-            println!("🤖 {}", response);
-            
-            // Check if Claude suggested an action we should take
-            if should_execute_command(&response) {
-                execute_suggested_command(&response).await?;
+
+    match claude.chat_stream(input, &context).await {
+        Ok(mut rx) => {
+            print!("🤖 ");
+            io::stdout().flush()?;
+
+            let mut response = String::new();
+            let mut stream_failed = false;
+            while let Some(chunk) = rx.recv().await {
+                match chunk {
+                    Ok(text) => {
+                        print!("{}", text);
+                        io::stdout().flush()?;
+                        response.push_str(&text);
+                    }
+                    Err(e) => {
+                        println!("\n⚠️ Claude streaming error: {}", e);
+                        stream_failed = true;
+                        break;
+                    }
+                }
+            }
+            println!();
+
+            if stream_failed && response.is_empty() {
+                println!("🔄 Falling back to built-in commands...");
+                handle_smart_input(input, coordinator).await?;
+            } else if should_execute_command(&response) {
+                execute_suggested_command(&response, coordinator).await?;
             }
         }
         Err(e) => {
             println!("⚠️ Claude unavailable: {}", e);
             println!("🔄 Falling back to built-in commands...");
-            handle_smart_input(input).await?;
+            handle_smart_input(input, coordinator).await?;
         }
     }
-    
+
     Ok(())
 }
 
@@ -346,19 +787,20 @@ fn should_execute_command(response: &str) -> bool {
     response.to_lowercase().contains("let me show")
 }
 
-async fn execute_suggested_command(response: &str) -> Result<()> {
+async fn execute_suggested_command(response: &str, coordinator: &coordinator::CoordinatorHandle) -> Result<()> {
     let lower_response = response.to_lowercase();
-    
+    let top_turtle = true; // only reached from the REPL's Claude fallback path
+
     if lower_response.contains("status") {
-        show_quick_status().await?;
+        show_quick_status(coordinator, top_turtle).await?;
     } else if lower_response.contains("work") {
-        show_work_dashboard().await?;
+        show_work_dashboard(coordinator, top_turtle).await?;
     } else if lower_response.contains("dashboard") {
-        show_full_dashboard().await?;
+        show_full_dashboard(coordinator, top_turtle).await?;
     } else if lower_response.contains("infrastructure") || lower_response.contains("infra") {
-        show_infrastructure_status().await?;
+        show_infrastructure_status(coordinator, top_turtle).await?;
     }
-    
+
     Ok(())
 }
 
@@ -418,29 +860,29 @@ async fn discover_turtle_fleet() -> Result<String> {
     }
 }
 
-async fn turtle_mode_transition() -> Result<()> {
+async fn turtle_mode_transition(coordinator: &coordinator::CoordinatorHandle, top_turtle: bool) -> Result<()> {
     println!("🐢 TURTLE MODE TRANSITION");
     println!("Transforming: pre-boot→standard or standard→secure_enclave");
-    
+
     // Check current state and transition appropriately
     let current_state = system::get_turtle_state().await?;
-    
+
     match current_state {
         TurtleState::PreBoot => {
             println!("🔄 Pre-boot → Standard mode transition");
-            system::transition_to_standard().await?;
-            show_quick_status().await?;
+            coordinator.transition_state(coordinator::StateTarget::Standard, top_turtle).await?;
+            show_quick_status(coordinator, top_turtle).await?;
         }
         TurtleState::Standard => {
             println!("🔒 Standard → Secure enclave transition");
-            system::transition_to_secure_enclave().await?;
-            show_infrastructure_status().await?;
+            coordinator.transition_state(coordinator::StateTarget::SecureEnclave, top_turtle).await?;
+            show_infrastructure_status(coordinator, top_turtle).await?;
         }
         TurtleState::SecureEnclave => {
             println!("🛡️ Already in secure enclave mode");
-            show_full_dashboard().await?;
+            show_full_dashboard(coordinator, top_turtle).await?;
         }
     }
-    
+
     Ok(())
 }
\ No newline at end of file
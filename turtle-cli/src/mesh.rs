@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures_util::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot, RwLock};
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+use crate::cnl_config::MeshPorts;
+
+pub type SeqId = u64;
+
+/// A feature a peer's handshake can advertise. Mirrors
+/// `MeshResilienceConfig.communication_redundancy`'s entries plus the four
+/// `MeshPorts` roles, so a version mismatch degrades to whatever overlap
+/// both sides negotiated rather than failing outright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Capability {
+    Discovery,
+    Coordination,
+    Observation,
+    Inference,
+    WebsocketPrimary,
+    WebrtcFallback,
+    HttpBackup,
+    DirectP2p,
+}
+
+/// Wire frame for the mesh protocol. `Request`/`Response` carry a `seq` the
+/// connection actor uses to route a reply back to the `oneshot` that's
+/// waiting for it; `Hello`/`Welcome` are the one-time capability-negotiation
+/// handshake.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Frame {
+    Hello { capabilities: HashSet<Capability> },
+    Welcome { capabilities: HashSet<Capability> },
+    Request { seq: SeqId, method: String, payload: serde_json::Value },
+    Response { seq: SeqId, payload: Result<serde_json::Value, String> },
+}
+
+/// One outstanding request: a frame to send, and where its reply goes.
+struct PendingRequest {
+    method: String,
+    payload: serde_json::Value,
+    reply_tx: oneshot::Sender<Result<serde_json::Value>>,
+}
+
+/// An async peer connection. A background task owns the actual socket and
+/// an inbox of `PendingRequest`s; `call` just hands one over and awaits the
+/// `oneshot` it gets back, so callers never touch the socket directly.
+pub struct MeshClient {
+    inbox: mpsc::Sender<PendingRequest>,
+    capabilities: Arc<RwLock<Option<HashSet<Capability>>>>,
+}
+
+impl MeshClient {
+    /// Connect to `peer_addr` (host:port, typically one of `MeshPorts`'
+    /// fields), trying the WebSocket primary first and falling back to the
+    /// HTTP backup transport per `node_failure_timeout`/`connection_retry_count`
+    /// from `MeshResilienceConfig` if it can't connect in time.
+    pub async fn connect(
+        peer_addr: &str,
+        local_capabilities: HashSet<Capability>,
+        node_failure_timeout: Duration,
+        connection_retry_count: u32,
+    ) -> Result<Self> {
+        let transport = Self::dial_with_retry(peer_addr, node_failure_timeout, connection_retry_count).await?;
+
+        let capabilities = Arc::new(RwLock::new(None));
+        let (inbox_tx, inbox_rx) = mpsc::channel(64);
+
+        tokio::spawn(Self::run(transport, local_capabilities, capabilities.clone(), inbox_rx));
+
+        Ok(MeshClient { inbox: inbox_tx, capabilities })
+    }
+
+    /// `WEBSOCKET_PRIMARY` first; if it can't connect within
+    /// `node_failure_timeout`, retry up to `connection_retry_count` times,
+    /// then drop to `HTTP_BACKUP`'s long-poll transport. `DIRECT_P2P` and
+    /// `WEBRTC_FALLBACK` are declared capabilities a peer can advertise in
+    /// its handshake, but aren't dialable transports themselves here.
+    async fn dial_with_retry(peer_addr: &str, node_failure_timeout: Duration, connection_retry_count: u32) -> Result<Transport> {
+        let ws_url = format!("ws://{}/mesh", peer_addr);
+
+        for attempt in 0..=connection_retry_count {
+            match tokio::time::timeout(node_failure_timeout, tokio_tungstenite::connect_async(&ws_url)).await {
+                Ok(Ok((stream, _))) => return Ok(Transport::WebSocket(stream)),
+                Ok(Err(e)) if attempt == connection_retry_count => {
+                    println!("⚠️ WebSocket primary to {} failed ({}), falling back to HTTP backup", peer_addr, e);
+                }
+                Err(_) if attempt == connection_retry_count => {
+                    println!(
+                        "⚠️ WebSocket primary to {} timed out after {:?}, falling back to HTTP backup",
+                        peer_addr, node_failure_timeout
+                    );
+                }
+                _ => continue, // not the last attempt yet - retry the dial
+            }
+        }
+
+        Ok(Transport::HttpBackup(HttpBackupTransport::new(peer_addr)))
+    }
+
+    /// The capabilities the peer's `Welcome` advertised, once the handshake
+    /// completes. `None` until then.
+    pub async fn peer_capabilities(&self) -> Option<HashSet<Capability>> {
+        self.capabilities.read().await.clone()
+    }
+
+    /// Send `method`/`payload` as a `Request` and await the matching
+    /// `Response` - the only thing callers (the dashboard, the background
+    /// workers) need to know about the transport underneath.
+    pub async fn call(&self, method: &str, payload: serde_json::Value) -> Result<serde_json::Value> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.inbox
+            .send(PendingRequest { method: method.to_string(), payload, reply_tx })
+            .await
+            .map_err(|_| anyhow::anyhow!("mesh connection actor has shut down"))?;
+        reply_rx.await.map_err(|_| anyhow::anyhow!("mesh connection actor dropped the reply"))?
+    }
+
+    /// The connection actor: owns the transport, performs the
+    /// capability-negotiation handshake, then loops writing outgoing
+    /// requests and routing incoming frames back to the `oneshot` waiting
+    /// on the matching `seq`.
+    async fn run(
+        mut transport: Transport,
+        local_capabilities: HashSet<Capability>,
+        capabilities: Arc<RwLock<Option<HashSet<Capability>>>>,
+        mut inbox: mpsc::Receiver<PendingRequest>,
+    ) {
+        if let Err(e) = transport.send_frame(&Frame::Hello { capabilities: local_capabilities }).await {
+            println!("⚠️ Mesh handshake failed to send Hello: {}", e);
+            return;
+        }
+        match transport.recv_frame().await {
+            Ok(RecvOutcome::Frame(Frame::Welcome { capabilities: peer_caps })) => {
+                *capabilities.write().await = Some(peer_caps);
+            }
+            Ok(RecvOutcome::Frame(other)) => {
+                println!("⚠️ Expected Welcome during mesh handshake, got {:?}", other);
+                return;
+            }
+            Ok(RecvOutcome::NoneYet) => {
+                println!("⚠️ Mesh handshake: no Welcome frame available yet");
+                return;
+            }
+            Ok(RecvOutcome::Closed) => {
+                println!("⚠️ Mesh handshake: peer closed the connection before sending Welcome");
+                return;
+            }
+            Err(e) => {
+                println!("⚠️ Mesh handshake failed waiting for Welcome: {}", e);
+                return;
+            }
+        }
+
+        let next_seq = AtomicU64::new(1);
+        let mut waiting: HashMap<SeqId, oneshot::Sender<Result<serde_json::Value>>> = HashMap::new();
+
+        loop {
+            tokio::select! {
+                outgoing = inbox.recv() => {
+                    let Some(request) = outgoing else { break };
+                    let seq = next_seq.fetch_add(1, Ordering::SeqCst);
+                    waiting.insert(seq, request.reply_tx);
+                    let frame = Frame::Request { seq, method: request.method, payload: request.payload };
+                    if let Err(e) = transport.send_frame(&frame).await {
+                        if let Some(reply_tx) = waiting.remove(&seq) {
+                            let _ = reply_tx.send(Err(anyhow::anyhow!("failed to write mesh request: {}", e)));
+                        }
+                    }
+                }
+                incoming = transport.recv_frame() => {
+                    match incoming {
+                        Ok(RecvOutcome::Frame(Frame::Response { seq, payload })) => {
+                            if let Some(reply_tx) = waiting.remove(&seq) {
+                                let _ = reply_tx.send(payload.map_err(|e| anyhow::anyhow!(e)));
+                            }
+                        }
+                        Ok(RecvOutcome::Frame(other)) => {
+                            println!("⚠️ Unexpected mesh frame after handshake: {:?}", other);
+                        }
+                        // HTTP backup's "nothing queued yet" (HTTP 204) - not a
+                        // disconnect, just poll again next tick.
+                        Ok(RecvOutcome::NoneYet) => {}
+                        Ok(RecvOutcome::Closed) => break,
+                        Err(e) => {
+                            println!("⚠️ Mesh connection error: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // The connection is gone - nobody still waiting will ever hear back.
+        for (_, reply_tx) in waiting {
+            let _ = reply_tx.send(Err(anyhow::anyhow!("mesh connection closed before a reply arrived")));
+        }
+    }
+}
+
+/// What a `Transport::recv_frame` call found. Kept distinct from a plain
+/// `Option<Frame>` because "nothing queued yet" and "the peer is gone" call
+/// for different reactions in `MeshClient::run`'s loop - the WebSocket
+/// transport only ever produces the latter, but the HTTP backup transport's
+/// 204-no-content poll result is the former, and conflating them tore down
+/// HTTP-backup connections on their very first empty poll.
+enum RecvOutcome {
+    Frame(Frame),
+    /// No frame available this poll - not a disconnect, just poll again.
+    NoneYet,
+    /// The peer closed the connection.
+    Closed,
+}
+
+/// The two redundancy layers this client actually dials.
+enum Transport {
+    WebSocket(tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>),
+    HttpBackup(HttpBackupTransport),
+}
+
+impl Transport {
+    async fn send_frame(&mut self, frame: &Frame) -> Result<()> {
+        let body = serde_json::to_string(frame)?;
+        match self {
+            Transport::WebSocket(ws) => Ok(ws.send(WsMessage::Text(body)).await?),
+            Transport::HttpBackup(http) => http.send_frame(body).await,
+        }
+    }
+
+    async fn recv_frame(&mut self) -> Result<RecvOutcome> {
+        match self {
+            Transport::WebSocket(ws) => match ws.next().await {
+                Some(Ok(WsMessage::Text(body))) => Ok(RecvOutcome::Frame(serde_json::from_str(&body)?)),
+                Some(Ok(WsMessage::Close(_))) | None => Ok(RecvOutcome::Closed),
+                Some(Ok(_)) => Ok(RecvOutcome::NoneYet), // ignore non-text frames (ping/pong/binary)
+                Some(Err(e)) => Err(e.into()),
+            },
+            Transport::HttpBackup(http) => http.recv_frame().await,
+        }
+    }
+}
+
+/// `HTTP_BACKUP`: a plain request/response long-poll over `reqwest` instead
+/// of a held-open socket. Outgoing frames POST to `/mesh/send`; incoming
+/// frames are long-polled from `/mesh/recv` one at a time, which is enough
+/// to keep `Transport`'s interface symmetric with the WebSocket case even
+/// though there's no persistent connection underneath.
+struct HttpBackupTransport {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HttpBackupTransport {
+    fn new(peer_addr: &str) -> Self {
+        HttpBackupTransport {
+            client: reqwest::Client::new(),
+            base_url: format!("http://{}", peer_addr),
+        }
+    }
+
+    async fn send_frame(&self, body: String) -> Result<()> {
+        self.client
+            .post(format!("{}/mesh/send", self.base_url))
+            .header("content-type", "application/json")
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    async fn recv_frame(&self) -> Result<RecvOutcome> {
+        let response = self.client.get(format!("{}/mesh/recv", self.base_url)).send().await?;
+        if response.status() == reqwest::StatusCode::NO_CONTENT {
+            return Ok(RecvOutcome::NoneYet);
+        }
+        let body = response.error_for_status()?.text().await?;
+        Ok(RecvOutcome::Frame(serde_json::from_str(&body)?))
+    }
+}
+
+/// Every role a peer can fill, from `MeshPorts` - used to pick which port a
+/// `MeshClient` dials for a given kind of call.
+pub fn port_for(ports: &MeshPorts, capability: Capability) -> Option<u16> {
+    match capability {
+        Capability::Discovery => Some(ports.discovery_port),
+        Capability::Coordination => Some(ports.coordination_port),
+        Capability::Observation => Some(ports.observation_port),
+        Capability::Inference => Some(ports.inference_port),
+        Capability::WebsocketPrimary | Capability::WebrtcFallback | Capability::HttpBackup | Capability::DirectP2p => None,
+    }
+}
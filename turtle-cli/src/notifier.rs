@@ -0,0 +1,149 @@
+use anyhow::Result;
+use reqwest::Client;
+use serde_json::json;
+
+use crate::cnl_config::TurtleFleetConfig;
+use crate::supervisor::ProcessEnd;
+
+#[derive(Debug, Clone)]
+pub enum Sink {
+    Desktop,
+    Webhook(String),
+    Stdout,
+}
+
+/// A meaningful state transition worth proactively alerting the operator
+/// about, instead of waiting for them to pull a dashboard command.
+#[derive(Debug, Clone)]
+pub enum Notification {
+    DcThresholdCrossed { dc: String, pct: u32 },
+    WorkerDied { name: String, outcome: ProcessEnd },
+    SshRecovered { dc: String },
+}
+
+impl Notification {
+    fn title(&self) -> &'static str {
+        match self {
+            Notification::DcThresholdCrossed { .. } => "🐢 DC deployment threshold crossed",
+            Notification::WorkerDied { .. } => "🐢 Supervised process died",
+            Notification::SshRecovered { .. } => "🐢 SSH connectivity recovered",
+        }
+    }
+
+    fn body(&self) -> String {
+        match self {
+            Notification::DcThresholdCrossed { dc, pct } => format!("{} is now {}% deployed", dc, pct),
+            Notification::WorkerDied { name, outcome } => format!("{}: {:?}", name, outcome),
+            Notification::SshRecovered { dc } => format!("SSH connectivity to {} is back", dc),
+        }
+    }
+
+    /// Routine updates (desktop-friendly) vs. critical ones an operator
+    /// would want routed to a webhook even if they're away from the desktop.
+    fn is_critical(&self) -> bool {
+        matches!(self, Notification::WorkerDied { .. })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NotifierConfig {
+    pub routine_sinks: Vec<Sink>,
+    pub critical_sinks: Vec<Sink>,
+    pub dc_deploy_thresholds: Vec<u32>,
+}
+
+impl NotifierConfig {
+    /// Build the sink routing from `cnl_config` so operators can, for
+    /// example, keep routine updates on the desktop while sending critical
+    /// DC1 SSH failures to a webhook.
+    pub fn from_cnl(config: &TurtleFleetConfig) -> Self {
+        let webhook = config
+            .safety_and_authority
+            .resource_usage_logging
+            .then(|| config.safety_and_authority.notification_webhook_url.clone())
+            .flatten()
+            .map(Sink::Webhook);
+
+        let mut critical_sinks = vec![Sink::Stdout];
+        if let Some(webhook) = webhook {
+            critical_sinks.push(webhook);
+        }
+
+        NotifierConfig {
+            routine_sinks: vec![Sink::Desktop, Sink::Stdout],
+            critical_sinks,
+            dc_deploy_thresholds: config.safety_and_authority.dc_deploy_thresholds.clone(),
+        }
+    }
+}
+
+impl Default for NotifierConfig {
+    fn default() -> Self {
+        NotifierConfig {
+            routine_sinks: vec![Sink::Desktop, Sink::Stdout],
+            critical_sinks: vec![Sink::Stdout],
+            dc_deploy_thresholds: vec![25, 50, 75, 100],
+        }
+    }
+}
+
+pub struct Notifier {
+    config: NotifierConfig,
+    client: Client,
+}
+
+impl Notifier {
+    pub fn new(config: NotifierConfig) -> Self {
+        Notifier {
+            config,
+            client: Client::new(),
+        }
+    }
+
+    /// `dc_deploy_thresholds` are crossed from below: only fire when the new
+    /// percentage clears a threshold the previous one hadn't.
+    pub fn crossed_threshold(&self, previous_pct: u32, new_pct: u32) -> Option<u32> {
+        self.config
+            .dc_deploy_thresholds
+            .iter()
+            .copied()
+            .find(|&t| previous_pct < t && new_pct >= t)
+    }
+
+    pub async fn notify(&self, event: Notification) -> Result<()> {
+        let sinks = if event.is_critical() {
+            &self.config.critical_sinks
+        } else {
+            &self.config.routine_sinks
+        };
+
+        for sink in sinks {
+            if let Err(e) = self.send(sink, &event).await {
+                println!("⚠️ Notification sink failed: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn send(&self, sink: &Sink, event: &Notification) -> Result<()> {
+        match sink {
+            Sink::Stdout => {
+                println!("🔔 {}: {}", event.title(), event.body());
+            }
+            Sink::Desktop => {
+                notify_rust::Notification::new()
+                    .summary(event.title())
+                    .body(&event.body())
+                    .show()?;
+            }
+            Sink::Webhook(url) => {
+                self.client
+                    .post(url)
+                    .json(&json!({ "title": event.title(), "body": event.body() }))
+                    .send()
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
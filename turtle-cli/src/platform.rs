@@ -0,0 +1,223 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::safety::{ProcessInfo, WindowInfo};
+
+/// OS-specific window/process inspection, selected at compile time the same
+/// way the standard library splits `sys/pal/{unix,windows,macos}`. Keeping
+/// the off-screen/size-change detection logic in `safety.rs` independent of
+/// how windows and processes are actually enumerated is what makes it
+/// portable instead of X11-only.
+#[async_trait]
+pub trait PlatformBackend {
+    async fn windows(&self) -> Result<Vec<WindowInfo>>;
+    async fn processes(&self) -> Result<Vec<ProcessInfo>>;
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod unix {
+    use super::*;
+    use tokio::process::Command;
+
+    pub struct UnixBackend;
+
+    #[async_trait]
+    impl PlatformBackend for UnixBackend {
+        async fn windows(&self) -> Result<Vec<WindowInfo>> {
+            match self.windows_wmctrl().await {
+                Ok(windows) if !windows.is_empty() => Ok(windows),
+                _ => self.windows_wayland_fallback().await,
+            }
+        }
+
+        async fn processes(&self) -> Result<Vec<ProcessInfo>> {
+            // Process enumeration is handled by sysinfo (see chunk1-1); the
+            // platform backend only owns windowing, which sysinfo can't do.
+            Ok(Vec::new())
+        }
+    }
+
+    impl UnixBackend {
+        async fn windows_wmctrl(&self) -> Result<Vec<WindowInfo>> {
+            let output = Command::new("wmctrl").args(&["-l", "-G"]).output().await?;
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut windows = Vec::new();
+
+            for line in stdout.lines() {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                if fields.len() >= 7 {
+                    if let (Ok(x), Ok(y), Ok(width), Ok(height)) = (
+                        fields[2].parse::<i32>(),
+                        fields[3].parse::<i32>(),
+                        fields[4].parse::<u32>(),
+                        fields[5].parse::<u32>(),
+                    ) {
+                        windows.push(WindowInfo {
+                            id: fields[0].to_string(),
+                            title: fields[7..].join(" "),
+                            x,
+                            y,
+                            width,
+                            height,
+                            visible: true,
+                        });
+                    }
+                }
+            }
+
+            Ok(windows)
+        }
+
+        /// `wmctrl` needs X11; under Wayland compositors (no X server, or
+        /// wmctrl missing/empty) we can't enumerate window geometry at all,
+        /// so report an empty list rather than erroring the whole observation.
+        async fn windows_wayland_fallback(&self) -> Result<Vec<WindowInfo>> {
+            Ok(Vec::new())
+        }
+    }
+}
+
+#[cfg(windows)]
+mod windows_backend {
+    use super::*;
+
+    pub struct WindowsBackend;
+
+    #[async_trait]
+    impl PlatformBackend for WindowsBackend {
+        async fn windows(&self) -> Result<Vec<WindowInfo>> {
+            // Enumerate top-level windows with EnumWindows, then read each
+            // one's rect (GetWindowRect) and title (GetWindowTextW).
+            use windows_sys::Win32::Foundation::{BOOL, LPARAM, RECT};
+            use windows_sys::Win32::UI::WindowsAndMessaging::{
+                EnumWindows, GetWindowRect, GetWindowTextLengthW, GetWindowTextW, IsWindowVisible,
+            };
+
+            unsafe extern "system" fn collect(hwnd: isize, lparam: LPARAM) -> BOOL {
+                let windows = &mut *(lparam as *mut Vec<WindowInfo>);
+                if IsWindowVisible(hwnd) == 0 {
+                    return 1;
+                }
+
+                let mut rect = RECT { left: 0, top: 0, right: 0, bottom: 0 };
+                if GetWindowRect(hwnd, &mut rect) == 0 {
+                    return 1;
+                }
+
+                let len = GetWindowTextLengthW(hwnd);
+                let mut buf = vec![0u16; (len + 1) as usize];
+                GetWindowTextW(hwnd, buf.as_mut_ptr(), len + 1);
+                let title = String::from_utf16_lossy(&buf[..len as usize]);
+
+                if !title.is_empty() {
+                    windows.push(WindowInfo {
+                        id: format!("{:x}", hwnd),
+                        title,
+                        x: rect.left,
+                        y: rect.top,
+                        width: (rect.right - rect.left).max(0) as u32,
+                        height: (rect.bottom - rect.top).max(0) as u32,
+                        visible: true,
+                    });
+                }
+                1
+            }
+
+            let mut windows: Vec<WindowInfo> = Vec::new();
+            unsafe {
+                EnumWindows(Some(collect), &mut windows as *mut _ as LPARAM);
+            }
+            Ok(windows)
+        }
+
+        async fn processes(&self) -> Result<Vec<ProcessInfo>> {
+            Ok(Vec::new())
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::*;
+    use core_foundation::array::CFArray;
+    use core_foundation::base::{CFType, TCFType};
+    use core_foundation::dictionary::CFDictionary;
+    use core_foundation::number::CFNumber;
+    use core_foundation::string::CFString;
+    use core_graphics::window::{
+        kCGNullWindowID, kCGWindowListOptionOnScreenOnly, CGWindowListCopyWindowInfo,
+    };
+
+    pub struct MacBackend;
+
+    #[async_trait]
+    impl PlatformBackend for MacBackend {
+        async fn windows(&self) -> Result<Vec<WindowInfo>> {
+            let info = unsafe {
+                CGWindowListCopyWindowInfo(kCGWindowListOptionOnScreenOnly, kCGNullWindowID)
+            };
+            // Entries are heterogeneous - a CFString title, a CFNumber window
+            // id, and a nested CFDictionary under kCGWindowBounds - so typing
+            // this as CFDictionary<CFString, CFString> silently dropped every
+            // non-string value, which is why geometry always came back 0x0.
+            let array: CFArray<CFDictionary<CFString, CFType>> = unsafe { TCFType::wrap_under_get_rule(info) };
+
+            let mut windows = Vec::new();
+            for dict in array.iter() {
+                let title = dict
+                    .find(CFString::new("kCGWindowName"))
+                    .and_then(|v| v.downcast::<CFString>())
+                    .map(|s| s.to_string())
+                    .unwrap_or_default();
+                let id = dict
+                    .find(CFString::new("kCGWindowNumber"))
+                    .and_then(|v| v.downcast::<CFNumber>())
+                    .and_then(|n| n.to_i64())
+                    .map(|n| n.to_string())
+                    .unwrap_or_default();
+
+                if title.is_empty() {
+                    continue;
+                }
+
+                let (x, y, width, height) = dict
+                    .find(CFString::new("kCGWindowBounds"))
+                    .and_then(|v| v.downcast::<CFDictionary<CFString, CFType>>())
+                    .map(|bounds| {
+                        let field = |key: &str| -> f64 {
+                            bounds
+                                .find(CFString::new(key))
+                                .and_then(|v| v.downcast::<CFNumber>())
+                                .and_then(|n| n.to_f64())
+                                .unwrap_or(0.0)
+                        };
+                        (field("X"), field("Y"), field("Width"), field("Height"))
+                    })
+                    .unwrap_or((0.0, 0.0, 0.0, 0.0));
+
+                windows.push(WindowInfo {
+                    id,
+                    title,
+                    x: x as i32,
+                    y: y as i32,
+                    width: width as u32,
+                    height: height as u32,
+                    visible: true,
+                });
+            }
+
+            Ok(windows)
+        }
+
+        async fn processes(&self) -> Result<Vec<ProcessInfo>> {
+            Ok(Vec::new())
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub use unix::UnixBackend as CurrentBackend;
+#[cfg(windows)]
+pub use windows_backend::WindowsBackend as CurrentBackend;
+#[cfg(target_os = "macos")]
+pub use macos::MacBackend as CurrentBackend;
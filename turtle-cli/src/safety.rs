@@ -2,7 +2,9 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 //use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::process::Command;
+use sysinfo::{DiskExt, NetworkExt, NetworksExt, PidExt, ProcessExt, System, SystemExt};
+
+use crate::platform::PlatformBackend;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemState {
@@ -53,22 +55,55 @@ pub struct SafetyContext {
     pub operation: String,
     pub target: String,
     pub risk_level: RiskLevel,
-    pub rollback_plan: Option<String>,
+    pub rollback_plan: Option<RollbackPlan>,
     pub monitoring_pattern: String,
+    /// `None` until `verify_safe_execution` runs; `Some(Ok(()))`/`Some(Err(_))`
+    /// once a rollback was actually attempted, so `operation_history` keeps a
+    /// real record instead of that outcome only ever reaching a `println!`.
+    pub rollback_outcome: Option<Result<(), String>>,
+}
+
+/// A concrete, replayable inverse action instead of a description of intent.
+/// `execute_with_monitoring` captures one of these from `pre_state` before
+/// running an operation, and replays it if `verify_safe_execution` flags harm.
+#[derive(Debug, Clone)]
+pub enum RollbackPlan {
+    RestoreWindowGeometry { id: String, x: i32, y: i32, width: u32, height: u32 },
+    RestartProcess { name: String, argv: Vec<String> },
+    RestoreFileFromBackup { path: String, backup: String },
+    Snapshot,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RiskLevel {
     Low,      // Read-only operations, status checks
-    Medium,   // Reversible modifications, window positioning  
+    Medium,   // Reversible modifications, window positioning
     High,     // File modifications, process management
     Critical, // System configuration, destructive operations
 }
 
+/// One step up the risk ladder - used to project what a simulated operation
+/// would escalate to if its predicted changes turned out to be harmful.
+fn escalate_risk(level: &RiskLevel) -> RiskLevel {
+    match level {
+        RiskLevel::Low => RiskLevel::Medium,
+        RiskLevel::Medium => RiskLevel::High,
+        RiskLevel::High => RiskLevel::Critical,
+        RiskLevel::Critical => RiskLevel::Critical,
+    }
+}
+
 pub struct CoreInteractionPrinciple {
     initial_state: Option<SystemState>,
     current_state: Option<SystemState>,
     operation_history: Vec<SafetyContext>,
+    // Held across observations: sysinfo's CPU usage needs two samples spaced
+    // by MINIMUM_CPU_UPDATE_INTERVAL, so a fresh System per call would always
+    // report 0%.
+    sys: System,
+    // Selected at compile time (see platform.rs) so window inspection works
+    // on Unix/X11, Windows, and macOS without touching this detection logic.
+    backend: crate::platform::CurrentBackend,
 }
 
 impl CoreInteractionPrinciple {
@@ -77,6 +112,8 @@ impl CoreInteractionPrinciple {
             initial_state: None,
             current_state: None,
             operation_history: Vec::new(),
+            sys: System::new_all(),
+            backend: crate::platform::CurrentBackend,
         }
     }
 
@@ -84,15 +121,21 @@ impl CoreInteractionPrinciple {
     pub async fn observe_system_state(&mut self) -> Result<SystemState> {
         println!("🔍 Observing current system state...");
         
+        self.sys.refresh_processes();
+        self.sys.refresh_cpu();
+        self.sys.refresh_memory();
+        self.sys.refresh_disks();
+        self.sys.refresh_networks();
+
         let state = SystemState {
             timestamp: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            processes: self.get_process_info().await?,
+            processes: self.get_process_info(),
             windows: self.get_window_info().await?,
-            resources: self.get_resource_state().await?,
-            network: self.get_network_state().await?,
+            resources: self.get_resource_state(),
+            network: self.get_network_state(),
         };
 
         if self.initial_state.is_none() {
@@ -118,11 +161,14 @@ impl CoreInteractionPrinciple {
             _ => RiskLevel::Medium,
         };
 
+        // A best-effort plan at analysis time, for operations with no real
+        // pre_state to capture yet; execute_with_monitoring overwrites this
+        // with a concrete plan built from the actual pre-execution state.
         let rollback_plan = match risk_level {
             RiskLevel::Low => None,
-            RiskLevel::Medium => Some(format!("Restore {} to original position/state", target)),
-            RiskLevel::High => Some(format!("Backup and restore {} if operation fails", target)), 
-            RiskLevel::Critical => Some(format!("Full system state snapshot for {}", target)),
+            RiskLevel::Medium => Some(RollbackPlan::Snapshot),
+            RiskLevel::High => Some(RollbackPlan::RestartProcess { name: target.to_string(), argv: vec![target.to_string()] }),
+            RiskLevel::Critical => Some(RollbackPlan::Snapshot),
         };
 
         let monitoring_pattern = match operation.to_lowercase().as_str() {
@@ -138,6 +184,7 @@ impl CoreInteractionPrinciple {
             risk_level,
             rollback_plan,
             monitoring_pattern,
+            rollback_outcome: None,
         };
 
         println!("📊 Risk Level: {:?}, Monitoring: {}", 
@@ -147,211 +194,281 @@ impl CoreInteractionPrinciple {
     }
 
     /// Step 3: Execute operation with minimal impact
-    pub async fn execute_with_monitoring<F, R>(&mut self, 
-        context: SafetyContext,
+    pub async fn execute_with_monitoring<F, R>(&mut self,
+        mut context: SafetyContext,
         operation: F
     ) -> Result<R>
     where
         F: std::future::Future<Output = Result<R>>,
     {
-        println!("⚡ Executing {} on {} with {} risk monitoring", 
-                 context.operation, context.target, 
+        println!("⚡ Executing {} on {} with {} risk monitoring",
+                 context.operation, context.target,
                  match context.risk_level {
                      RiskLevel::Low => "LOW",
-                     RiskLevel::Medium => "MEDIUM", 
+                     RiskLevel::Medium => "MEDIUM",
                      RiskLevel::High => "HIGH",
                      RiskLevel::Critical => "CRITICAL",
                  });
 
         // Store pre-execution state for rollback
         let pre_state = self.observe_system_state().await?;
-        
+
+        // Capture a concrete, replayable plan from the actual pre-state
+        // rather than relying on the caller's best-effort guess.
+        context.rollback_plan = self.capture_rollback_plan(&pre_state, &context);
+
         // Execute the operation
         let result = operation.await;
-        
+
         // Monitor for changes
         let post_state = self.observe_system_state().await?;
-        
-        // Check for unintended effects
-        self.verify_safe_execution(&pre_state, &post_state, &context).await?;
-        
+
+        // Check for unintended effects, replaying the rollback plan if harm is detected
+        self.verify_safe_execution(&pre_state, &post_state, &mut context).await?;
+
         // Record successful operation
         self.operation_history.push(context);
-        
+
         result
     }
 
+    /// Capture a concrete rollback plan from `pre_state`, matching the
+    /// operation's monitoring pattern.
+    fn capture_rollback_plan(&self, pre_state: &SystemState, context: &SafetyContext) -> Option<RollbackPlan> {
+        match context.monitoring_pattern.as_str() {
+            "window_position_changes" => pre_state
+                .windows
+                .iter()
+                .find(|w| w.title.eq_ignore_ascii_case(&context.target))
+                .map(|w| RollbackPlan::RestoreWindowGeometry {
+                    id: w.id.clone(),
+                    x: w.x,
+                    y: w.y,
+                    width: w.width,
+                    height: w.height,
+                }),
+            "process_state_changes" => Some(RollbackPlan::RestartProcess {
+                name: context.target.clone(),
+                argv: vec![context.target.clone()],
+            }),
+            _ => Some(RollbackPlan::Snapshot),
+        }
+    }
+
     /// Step 4: Verify execution was safe and no harm occurred
     async fn verify_safe_execution(
-        &self, 
-        pre_state: &SystemState, 
+        &mut self,
+        pre_state: &SystemState,
         post_state: &SystemState,
-        context: &SafetyContext
+        context: &mut SafetyContext
     ) -> Result<()> {
         println!("🔍 Verifying safe execution...");
+        let (warnings, harm_detected) = self.evaluate_changes(pre_state, post_state, context);
 
-        // Check for unexpected process changes
-        let process_changes = self.detect_process_changes(pre_state, post_state);
-        if !process_changes.is_empty() && context.risk_level == RiskLevel::Low {
-            println!("⚠️ Unexpected process changes detected during low-risk operation");
-            for change in process_changes {
-                println!("   - {}", change);
+        if warnings.is_empty() {
+            println!("✅ Execution verification complete - no harmful effects detected");
+        } else {
+            for warning in &warnings {
+                println!("⚠️ {}", warning);
             }
         }
 
-        // Check for resource spikes
-        if post_state.resources.cpu_percent > 80.0 && 
-           pre_state.resources.cpu_percent < 50.0 {
-            println!("⚠️ High CPU usage detected after operation");
-        }
+        if harm_detected {
+            let (reason, pids): (&str, Vec<u32>) = if context.monitoring_pattern == "window_position_changes" {
+                ("window positioning issue", Vec::new())
+            } else {
+                ("unexpected process changes", post_state.processes.iter().map(|p| p.pid).collect())
+            };
+            if let Err(e) = self.take_snapshot(reason, &pids, pre_state, post_state) {
+                println!("⚠️ Failed to write diagnostic snapshot: {}", e);
+            }
 
-        // Check for window management issues
-        if context.monitoring_pattern == "window_position_changes" {
-            let window_issues = self.detect_window_issues(pre_state, post_state);
-            if !window_issues.is_empty() {
-                println!("⚠️ Window positioning issues detected:");
-                for issue in window_issues {
-                    println!("   - {}", issue);
+            if let Some(plan) = &context.rollback_plan {
+                println!("↩️ Harm detected, replaying rollback plan: {:?}", plan);
+                let outcome = self.apply_rollback(plan).await;
+                match &outcome {
+                    Ok(()) => println!("✅ Rollback succeeded"),
+                    Err(e) => println!("❌ Rollback failed: {}", e),
                 }
+                context.rollback_outcome = Some(outcome.map_err(|e| e.to_string()));
+            }
+        }
+
+        // Resource spikes are logged and snapshotted but don't gate rollback.
+        if post_state.resources.cpu_percent > 80.0 && pre_state.resources.cpu_percent < 50.0 {
+            let top_pids: Vec<u32> = {
+                let mut procs = post_state.processes.clone();
+                procs.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
+                procs.into_iter().take(5).map(|p| p.pid).collect()
+            };
+            if let Err(e) = self.take_snapshot("CPU usage spike", &top_pids, pre_state, post_state) {
+                println!("⚠️ Failed to write diagnostic snapshot: {}", e);
             }
         }
 
-        println!("✅ Execution verification complete - no harmful effects detected");
         Ok(())
     }
 
-    async fn get_process_info(&self) -> Result<Vec<ProcessInfo>> {
-        let output = Command::new("ps")
-            .args(&["aux", "--no-headers"])
-            .output()
-            .await?;
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut processes = Vec::new();
-
-        for line in stdout.lines().take(20) { // Limit to top 20 processes
-            let fields: Vec<&str> = line.split_whitespace().collect();
-            if fields.len() >= 11 {
-                if let (Ok(pid), Ok(cpu), Ok(mem)) = (
-                    fields[1].parse::<u32>(),
-                    fields[2].parse::<f32>(),
-                    fields[5].parse::<u32>(),
-                ) {
-                    processes.push(ProcessInfo {
-                        pid,
-                        name: fields[10].to_string(),
-                        cpu_usage: cpu,
-                        memory_mb: mem / 1024, // Convert KB to MB
-                        status: fields[7].to_string(),
-                    });
-                }
+    /// Decide what `post` relative to `pre` means for `context`: the
+    /// warnings it produces and whether it counts as harm requiring
+    /// rollback. Pure and synchronous so it works on any `(pre, post)` pair -
+    /// a real observation from `verify_safe_execution`, or a simulated one
+    /// from `simulate_execution` - under the same rules.
+    fn evaluate_changes(&self, pre: &SystemState, post: &SystemState, context: &SafetyContext) -> (Vec<String>, bool) {
+        let mut warnings = Vec::new();
+        let mut harm_detected = false;
+
+        let process_changes = self.detect_process_changes(pre, post);
+        if !process_changes.is_empty() {
+            if context.risk_level == RiskLevel::Low {
+                warnings.push("Unexpected process changes detected during low-risk operation".to_string());
+            }
+            warnings.extend(process_changes);
+            if matches!(context.risk_level, RiskLevel::Medium | RiskLevel::High) {
+                harm_detected = true;
             }
         }
 
-        Ok(processes)
-    }
+        if post.resources.cpu_percent > 80.0 && pre.resources.cpu_percent < 50.0 {
+            warnings.push("High CPU usage detected after operation".to_string());
+        }
 
-    async fn get_window_info(&self) -> Result<Vec<WindowInfo>> {
-        let output = Command::new("wmctrl")
-            .args(&["-l", "-G"])
-            .output()
-            .await?;
-
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut windows = Vec::new();
-
-        for line in stdout.lines() {
-            let fields: Vec<&str> = line.split_whitespace().collect();
-            if fields.len() >= 7 {
-                if let (Ok(x), Ok(y), Ok(width), Ok(height)) = (
-                    fields[2].parse::<i32>(),
-                    fields[3].parse::<i32>(),
-                    fields[4].parse::<u32>(),
-                    fields[5].parse::<u32>(),
-                ) {
-                    windows.push(WindowInfo {
-                        id: fields[0].to_string(),
-                        title: fields[7..].join(" "),
-                        x,
-                        y,
-                        width,
-                        height,
-                        visible: true, // Assume visible if wmctrl can see it
-                    });
-                }
+        if context.monitoring_pattern == "window_position_changes" {
+            let window_issues = self.detect_window_issues(pre, post);
+            if !window_issues.is_empty() {
+                warnings.extend(window_issues);
+                harm_detected = true;
             }
         }
 
-        Ok(windows)
+        (warnings, harm_detected)
     }
 
-    async fn get_resource_state(&self) -> Result<ResourceState> {
-        // Get CPU usage from /proc/loadavg
-        let loadavg_output = Command::new("cat")
-            .arg("/proc/loadavg")
-            .output()
-            .await?;
-        
-        let loadavg_str = String::from_utf8_lossy(&loadavg_output.stdout);
-        let load_average = loadavg_str
-            .split_whitespace()
-            .next()
-            .and_then(|s| s.parse::<f32>().ok())
-            .unwrap_or(0.0);
-
-        // Get memory usage from /proc/meminfo
-        let meminfo_output = Command::new("cat")
-            .arg("/proc/meminfo")
-            .output()
-            .await?;
-        
-        let meminfo_str = String::from_utf8_lossy(&meminfo_output.stdout);
-        let mut mem_total = 0u64;
-        let mut mem_available = 0u64;
-        
-        for line in meminfo_str.lines() {
-            if line.starts_with("MemTotal:") {
-                mem_total = line.split_whitespace()
-                    .nth(1)
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(0);
-            } else if line.starts_with("MemAvailable:") {
-                mem_available = line.split_whitespace()
-                    .nth(1)
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(0);
+    /// Preview a `High`/`Critical` operation before it runs: apply `delta` to
+    /// a clone of `pre_state` and run the same checks `verify_safe_execution`
+    /// would, without observing or touching the real system. Returns the
+    /// predicted warnings and how far the risk level would escalate if the
+    /// operation actually ran.
+    pub fn simulate_execution(
+        &self,
+        pre_state: &SystemState,
+        context: &SafetyContext,
+        delta: &crate::simulation::SystemStateDelta,
+    ) -> (Vec<String>, RiskLevel) {
+        let post_state = delta.apply(pre_state);
+        let (mut warnings, harm_detected) = self.evaluate_changes(pre_state, &post_state, context);
+        warnings.extend(delta.declarative_notes());
+
+        let projected_risk = if harm_detected {
+            escalate_risk(&context.risk_level)
+        } else {
+            context.risk_level.clone()
+        };
+
+        (warnings, projected_risk)
+    }
+
+    /// Read the offending process(es) plus the full pre/post state diff and
+    /// write it as timestamped JSON, so operators get a forensic artifact
+    /// instead of a transient console line.
+    pub fn take_snapshot(&self, reason: &str, pids: &[u32], pre_state: &SystemState, post_state: &SystemState) -> Result<std::path::PathBuf> {
+        let snapshot = crate::diagnostics::DiagnosticSnapshot::capture(reason, pids, pre_state, post_state);
+        let path = snapshot.write(&crate::diagnostics::default_dir())?;
+        println!("🧾 Diagnostic snapshot written: {}", path.display());
+        Ok(path)
+    }
+
+    /// Replay the inverse of a flagged operation.
+    async fn apply_rollback(&self, plan: &RollbackPlan) -> Result<()> {
+        match plan {
+            RollbackPlan::RestoreWindowGeometry { id, x, y, width, height } => {
+                #[cfg(unix)]
+                {
+                    tokio::process::Command::new("wmctrl")
+                        .args(&["-i", "-r", id, "-e", &format!("0,{},{},{},{}", x, y, width, height)])
+                        .output()
+                        .await?;
+                }
+                #[cfg(not(unix))]
+                {
+                    println!("(no window-move backend on this platform for {})", id);
+                }
+                Ok(())
+            }
+            RollbackPlan::RestartProcess { name, argv } => {
+                let args: Vec<&str> = argv.iter().skip(1).map(|s| s.as_str()).collect();
+                tokio::process::Command::new(name).args(&args).spawn()?;
+                Ok(())
+            }
+            RollbackPlan::RestoreFileFromBackup { path, backup } => {
+                std::fs::copy(backup, path)?;
+                Ok(())
+            }
+            RollbackPlan::Snapshot => {
+                println!("(snapshot-only rollback plan - no automatic action to replay)");
+                Ok(())
             }
         }
+    }
+
+    fn get_process_info(&self) -> Vec<ProcessInfo> {
+        self.sys
+            .processes()
+            .values()
+            .take(20) // Limit to top 20 processes
+            .map(|p| ProcessInfo {
+                pid: p.pid().as_u32(),
+                name: p.name().to_string(),
+                cpu_usage: p.cpu_usage(),
+                memory_mb: (p.memory() / 1024 / 1024) as u32, // bytes -> MB
+                status: p.status().to_string(),
+            })
+            .collect()
+    }
 
-        let memory_percent = if mem_total > 0 {
-            ((mem_total - mem_available) as f32 / mem_total as f32) * 100.0
+    async fn get_window_info(&self) -> Result<Vec<WindowInfo>> {
+        self.backend.windows().await
+    }
+
+    fn get_resource_state(&self) -> ResourceState {
+        let memory_percent = if self.sys.total_memory() > 0 {
+            (self.sys.used_memory() as f32 / self.sys.total_memory() as f32) * 100.0
         } else {
             0.0
         };
 
-        Ok(ResourceState {
-            cpu_percent: load_average * 25.0, // Rough approximation
+        let (disk_used, disk_total) = self.sys.disks().iter().fold((0u64, 0u64), |(used, total), disk| {
+            let disk_total = disk.total_space();
+            let disk_used = disk_total.saturating_sub(disk.available_space());
+            (used + disk_used, total + disk_total)
+        });
+        let disk_percent = if disk_total > 0 {
+            (disk_used as f32 / disk_total as f32) * 100.0
+        } else {
+            0.0
+        };
+
+        ResourceState {
+            cpu_percent: self.sys.global_cpu_info().cpu_usage(),
             memory_percent,
-            disk_percent: 0.0, // TODO: Implement disk usage checking
-            load_average,
-        })
+            disk_percent,
+            load_average: self.sys.load_average().one as f32,
+        }
     }
 
-    async fn get_network_state(&self) -> Result<NetworkState> {
-        // Simple connectivity check
-        let ping_result = Command::new("ping")
-            .args(&["-c", "1", "-W", "2", "8.8.8.8"])
-            .output()
-            .await;
-
-        let connected = ping_result.is_ok() && 
-            ping_result.unwrap().status.success();
+    fn get_network_state(&self) -> NetworkState {
+        let active_connections = self.sys.networks().iter().count() as u32;
+        let connected = self
+            .sys
+            .networks()
+            .iter()
+            .any(|(_, data)| data.total_received() > 0 || data.total_transmitted() > 0);
 
-        Ok(NetworkState {
+        NetworkState {
             connected,
-            latency_ms: None, // TODO: Parse ping output for latency
-            active_connections: 0, // TODO: Parse netstat output
-        })
+            latency_ms: None, // sysinfo doesn't probe latency; left for a future health-check pass
+            active_connections,
+        }
     }
 
     fn detect_process_changes(&self, pre: &SystemState, post: &SystemState) -> Vec<String> {
@@ -399,4 +516,149 @@ impl CoreInteractionPrinciple {
         
         issues
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_state() -> SystemState {
+        SystemState {
+            timestamp: 0,
+            processes: Vec::new(),
+            windows: Vec::new(),
+            resources: ResourceState { cpu_percent: 0.0, memory_percent: 0.0, disk_percent: 0.0, load_average: 0.0 },
+            network: NetworkState { connected: true, latency_ms: None, active_connections: 0 },
+        }
+    }
+
+    fn context(monitoring_pattern: &str, target: &str) -> SafetyContext {
+        SafetyContext {
+            operation: "move".to_string(),
+            target: target.to_string(),
+            risk_level: RiskLevel::Medium,
+            rollback_plan: None,
+            monitoring_pattern: monitoring_pattern.to_string(),
+            rollback_outcome: None,
+        }
+    }
+
+    #[test]
+    fn capture_rollback_plan_window_pattern_finds_matching_window_by_title() {
+        let principle = CoreInteractionPrinciple::new();
+        let mut pre_state = empty_state();
+        pre_state.windows.push(WindowInfo {
+            id: "0x123".to_string(),
+            title: "Firefox".to_string(),
+            x: 10,
+            y: 20,
+            width: 800,
+            height: 600,
+            visible: true,
+        });
+        let context = context("window_position_changes", "firefox");
+
+        let plan = principle.capture_rollback_plan(&pre_state, &context);
+
+        match plan {
+            Some(RollbackPlan::RestoreWindowGeometry { id, x, y, width, height }) => {
+                assert_eq!(id, "0x123");
+                assert_eq!((x, y, width, height), (10, 20, 800, 600));
+            }
+            other => panic!("expected RestoreWindowGeometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn capture_rollback_plan_window_pattern_no_match_returns_none() {
+        let principle = CoreInteractionPrinciple::new();
+        let pre_state = empty_state();
+        let context = context("window_position_changes", "nonexistent");
+
+        assert!(principle.capture_rollback_plan(&pre_state, &context).is_none());
+    }
+
+    #[test]
+    fn capture_rollback_plan_process_pattern_restarts_target() {
+        let principle = CoreInteractionPrinciple::new();
+        let pre_state = empty_state();
+        let context = context("process_state_changes", "nginx");
+
+        match principle.capture_rollback_plan(&pre_state, &context) {
+            Some(RollbackPlan::RestartProcess { name, argv }) => {
+                assert_eq!(name, "nginx");
+                assert_eq!(argv, vec!["nginx".to_string()]);
+            }
+            other => panic!("expected RestartProcess, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn capture_rollback_plan_unknown_pattern_falls_back_to_snapshot() {
+        let principle = CoreInteractionPrinciple::new();
+        let pre_state = empty_state();
+        let context = context("general_system_changes", "whatever");
+
+        assert!(matches!(principle.capture_rollback_plan(&pre_state, &context), Some(RollbackPlan::Snapshot)));
+    }
+
+    #[tokio::test]
+    async fn apply_rollback_snapshot_plan_is_a_noop_ok() {
+        let principle = CoreInteractionPrinciple::new();
+        assert!(principle.apply_rollback(&RollbackPlan::Snapshot).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn apply_rollback_restore_file_from_backup_copies_backup_over_path() {
+        let principle = CoreInteractionPrinciple::new();
+        let dir = std::env::temp_dir();
+        let backup = dir.join(format!("turtle_test_backup_{}.txt", std::process::id()));
+        let path = dir.join(format!("turtle_test_restored_{}.txt", std::process::id()));
+        std::fs::write(&backup, b"known good contents").unwrap();
+
+        let plan = RollbackPlan::RestoreFileFromBackup {
+            path: path.to_string_lossy().to_string(),
+            backup: backup.to_string_lossy().to_string(),
+        };
+        let result = principle.apply_rollback(&plan).await;
+
+        assert!(result.is_ok());
+        assert_eq!(std::fs::read(&path).unwrap(), b"known good contents");
+
+        let _ = std::fs::remove_file(&backup);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn verify_safe_execution_records_rollback_outcome_when_harm_detected() {
+        let mut principle = CoreInteractionPrinciple::new();
+        let mut post_state = empty_state();
+        // A window that moved off-screen is exactly what `detect_window_issues`
+        // flags as harm for a `window_position_changes` operation.
+        post_state.windows.push(WindowInfo {
+            id: "0x1".to_string(),
+            title: "term".to_string(),
+            x: -500,
+            y: 0,
+            width: 400,
+            height: 300,
+            visible: true,
+        });
+        let mut pre_with_window = empty_state();
+        pre_with_window.windows.push(WindowInfo {
+            id: "0x1".to_string(),
+            title: "term".to_string(),
+            x: 0,
+            y: 0,
+            width: 400,
+            height: 300,
+            visible: true,
+        });
+        let mut context = context("window_position_changes", "term");
+        context.rollback_plan = Some(RollbackPlan::Snapshot);
+
+        principle.verify_safe_execution(&pre_with_window, &post_state, &mut context).await.unwrap();
+
+        assert_eq!(context.rollback_outcome, Some(Ok(())));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,299 @@
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use chrono::{Local, Timelike};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+
+use crate::coordinator::{CoordinatorHandle, DashboardMode};
+use crate::work;
+
+pub type EntryId = u64;
+
+/// What a fired entry does. Kept as plain data rather than a boxed closure
+/// so the run loop can match on it directly instead of threading
+/// `Box<dyn Fn>` futures through a channel.
+#[derive(Debug, Clone)]
+pub enum ScheduledAction {
+    EndFocusMode,
+    FlipToWorkMode,
+    FlipToGeneralMode,
+    EndOfDaySummary,
+}
+
+struct Entry {
+    id: EntryId,
+    label: String,
+    fire_at: Instant,
+    recurrence: Option<Duration>,
+    action: ScheduledAction,
+}
+
+enum ControlMsg {
+    Add {
+        label: String,
+        delay: Duration,
+        recurrence: Option<Duration>,
+        action: ScheduledAction,
+        reply: oneshot::Sender<EntryId>,
+    },
+    Cancel {
+        id: EntryId,
+        reply: oneshot::Sender<bool>,
+    },
+    List {
+        reply: oneshot::Sender<Vec<(EntryId, String, Duration)>>,
+    },
+}
+
+/// A handle to the running scheduler task. Cheap to clone, same pattern as
+/// `CoordinatorHandle`.
+#[derive(Clone)]
+pub struct SchedulerHandle {
+    tx: mpsc::Sender<ControlMsg>,
+}
+
+impl SchedulerHandle {
+    pub async fn schedule_once(&self, label: impl Into<String>, delay: Duration, action: ScheduledAction) -> Result<EntryId> {
+        self.add(label.into(), delay, None, action).await
+    }
+
+    pub async fn schedule_recurring(
+        &self,
+        label: impl Into<String>,
+        delay: Duration,
+        interval: Duration,
+        action: ScheduledAction,
+    ) -> Result<EntryId> {
+        self.add(label.into(), delay, Some(interval), action).await
+    }
+
+    async fn add(&self, label: String, delay: Duration, recurrence: Option<Duration>, action: ScheduledAction) -> Result<EntryId> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(ControlMsg::Add { label, delay, recurrence, action, reply })
+            .await
+            .map_err(|_| anyhow::anyhow!("scheduler task has shut down"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("scheduler dropped the reply"))
+    }
+
+    pub async fn cancel(&self, id: EntryId) -> Result<bool> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(ControlMsg::Cancel { id, reply })
+            .await
+            .map_err(|_| anyhow::anyhow!("scheduler task has shut down"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("scheduler dropped the reply"))
+    }
+
+    /// Each entry's id, label, and time remaining until it next fires.
+    pub async fn list(&self) -> Result<Vec<(EntryId, String, Duration)>> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(ControlMsg::List { reply })
+            .await
+            .map_err(|_| anyhow::anyhow!("scheduler task has shut down"))?;
+        rx.await.map_err(|_| anyhow::anyhow!("scheduler dropped the reply"))
+    }
+}
+
+/// Spawn the scheduler's run loop and return a handle to it. `coordinator`
+/// is how actions that touch fleet state (dashboard mode flips) are carried
+/// out - same path every other fleet-state mutation goes through.
+pub fn spawn(coordinator: CoordinatorHandle) -> SchedulerHandle {
+    let (tx, rx) = mpsc::channel(32);
+    tokio::spawn(run(rx, coordinator));
+    SchedulerHandle { tx }
+}
+
+/// Seeds the daily recurring entries implied by `StartupBehaviorConfig`:
+/// flip to work mode at `work_hours_start`, flip to general mode and fire
+/// the end-of-day summary at `work_hours_end`.
+pub async fn seed_startup_schedule(scheduler: &SchedulerHandle, startup: &crate::cnl_config::StartupBehaviorConfig) -> Result<()> {
+    let day = Duration::from_secs(24 * 3600);
+    scheduler
+        .schedule_recurring("work hours start", duration_until_daily(startup.work_hours_start), day, ScheduledAction::FlipToWorkMode)
+        .await?;
+    scheduler
+        .schedule_recurring("work hours end", duration_until_daily(startup.work_hours_end), day, ScheduledAction::FlipToGeneralMode)
+        .await?;
+    scheduler
+        .schedule_recurring("end of day summary", duration_until_daily(startup.work_hours_end), day, ScheduledAction::EndOfDaySummary)
+        .await?;
+    Ok(())
+}
+
+/// The run loop: sleep until the soonest entry's `fire_at`, fire it, push a
+/// new entry for it if it recurs, then go back to sleeping. A control
+/// message (add/cancel/list) interrupts the sleep so new entries are picked
+/// up immediately instead of only after the next fire.
+async fn run(mut rx: mpsc::Receiver<ControlMsg>, coordinator: CoordinatorHandle) {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut next_id: EntryId = 1;
+
+    loop {
+        let soonest = entries.iter().map(|e| e.fire_at).min();
+
+        tokio::select! {
+            _ = tokio::time::sleep_until(soonest.unwrap_or_else(|| Instant::now() + Duration::from_secs(3600))), if soonest.is_some() => {
+                let now = Instant::now();
+                let (due, pending): (Vec<Entry>, Vec<Entry>) = entries.into_iter().partition(|e| e.fire_at <= now);
+                entries = pending;
+                for entry in due {
+                    fire(&entry.action, &coordinator).await;
+                    if let Some(interval) = entry.recurrence {
+                        entries.push(Entry { fire_at: entry.fire_at + interval, ..entry });
+                    }
+                }
+            }
+            control = rx.recv() => {
+                let Some(control) = control else { break };
+                match control {
+                    ControlMsg::Add { label, delay, recurrence, action, reply } => {
+                        let id = next_id;
+                        next_id += 1;
+                        entries.push(Entry { id, label, fire_at: Instant::now() + delay, recurrence, action });
+                        let _ = reply.send(id);
+                    }
+                    ControlMsg::Cancel { id, reply } => {
+                        let before = entries.len();
+                        entries.retain(|e| e.id != id);
+                        let _ = reply.send(entries.len() != before);
+                    }
+                    ControlMsg::List { reply } => {
+                        let now = Instant::now();
+                        let snapshot = entries
+                            .iter()
+                            .map(|e| (e.id, e.label.clone(), e.fire_at.saturating_duration_since(now)))
+                            .collect();
+                        let _ = reply.send(snapshot);
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn fire(action: &ScheduledAction, coordinator: &CoordinatorHandle) {
+    match action {
+        ScheduledAction::EndFocusMode => {
+            println!("⏰ Focus session ended");
+        }
+        ScheduledAction::FlipToWorkMode => {
+            println!("🎯 Work hours started - switching to work mode");
+            if let Err(e) = coordinator.show_dashboard(DashboardMode::WorkFocused, true).await {
+                println!("⚠️ Scheduled work-mode switch failed: {}", e);
+            }
+        }
+        ScheduledAction::FlipToGeneralMode => {
+            println!("📊 Work hours ended - switching to general mode");
+            if let Err(e) = coordinator.show_dashboard(DashboardMode::Compact, true).await {
+                println!("⚠️ Scheduled general-mode switch failed: {}", e);
+            }
+        }
+        ScheduledAction::EndOfDaySummary => {
+            if let Err(e) = work::end_of_day().await {
+                println!("⚠️ Scheduled end-of-day summary failed: {}", e);
+            }
+        }
+    }
+}
+
+/// Parses `"45m"`/`"2h"`-style duration strings: an integer followed by a
+/// single `s`/`m`/`h`/`d` unit suffix.
+pub fn parse_duration(spec: &str) -> Result<Duration> {
+    let spec = spec.trim();
+    if spec.len() < 2 {
+        bail!("invalid duration '{}' - expected e.g. '45m' or '2h'", spec);
+    }
+    let (digits, suffix) = spec.split_at(spec.len() - 1);
+
+    let Ok(amount) = digits.parse::<u64>() else {
+        bail!("invalid duration '{}' - expected e.g. '45m' or '2h'", spec);
+    };
+
+    let seconds = match suffix.to_lowercase().as_str() {
+        "s" => amount,
+        "m" => amount * 60,
+        "h" => amount * 3600,
+        "d" => amount * 86400,
+        other => bail!("invalid duration unit '{}' in '{}' - expected s/m/h/d", other, spec),
+    };
+
+    Ok(Duration::from_secs(seconds))
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h{}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+/// Time from now until the next occurrence of `hour:00` local time - today
+/// if that hour hasn't happened yet, otherwise tomorrow. This approximates
+/// to the top of the hour rather than acting as a precise alarm clock, which
+/// is good enough for the work/general mode flip and end-of-day trigger.
+fn duration_until_daily(hour: u32) -> Duration {
+    let now = Local::now();
+    let hour = hour % 24;
+    let hours_until = match (hour + 24 - now.hour()) % 24 {
+        0 => 24,
+        h => h,
+    };
+    let elapsed_this_hour = (now.minute() * 60 + now.second()) as u64;
+    Duration::from_secs((hours_until as u64 * 3600).saturating_sub(elapsed_this_hour))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_single_char_input_fails() {
+        assert!(parse_duration("m").is_err());
+    }
+
+    #[test]
+    fn parse_duration_unknown_suffix_fails() {
+        let err = parse_duration("10x").unwrap_err();
+        assert!(err.to_string().contains("expected s/m/h/d"));
+    }
+
+    #[test]
+    fn parse_duration_zero_minutes() {
+        assert_eq!(parse_duration("0m").unwrap(), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn parse_duration_units() {
+        assert_eq!(parse_duration("45s").unwrap(), Duration::from_secs(45));
+        assert_eq!(parse_duration("45m").unwrap(), Duration::from_secs(45 * 60));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::from_secs(2 * 3600));
+        assert_eq!(parse_duration("1d").unwrap(), Duration::from_secs(86400));
+    }
+
+    #[test]
+    fn parse_duration_non_numeric_amount_fails() {
+        assert!(parse_duration("xxs").is_err());
+    }
+
+    #[test]
+    fn duration_until_daily_never_exceeds_a_day() {
+        for hour in [0, 1, 12, 23, 24, 36] {
+            assert!(duration_until_daily(hour) <= Duration::from_secs(24 * 3600));
+        }
+    }
+
+    #[test]
+    fn duration_until_daily_wraps_hours_above_23() {
+        // `25 % 24 == 1`, so the two should always land within the same minute.
+        let wrapped = duration_until_daily(25).as_secs();
+        let direct = duration_until_daily(1).as_secs();
+        assert!(wrapped.abs_diff(direct) <= 1);
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::command_parser::WindowGeometry;
+
+const SESSION_DIR: &str = "turtle_sessions";
+
+/// One observed window tagged with the CNL channel that was tracking it -
+/// the same string `generate_monitoring_pattern` derives from a command's
+/// `CommandIntent` (e.g. `"top_turtle_session_monitoring"`,
+/// `"fleet_interaction_tracking"`), so a reloaded snapshot still says which
+/// kind of session produced each window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedWindow {
+    pub channel: String,
+    pub geometry: WindowGeometry,
+}
+
+/// A reproducible dump of every window a session observed, in the
+/// `config.ron` convention the rest of the turtle ecosystem uses. Reload it
+/// with `load` and feed the geometries back through `layout::tile` and a
+/// `GeometryBackend` to replay a fleet interaction offline instead of only
+/// being able to inspect it live.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub timestamp: u64,
+    pub windows: Vec<TrackedWindow>,
+}
+
+impl SessionSnapshot {
+    pub fn capture(windows: Vec<TrackedWindow>) -> Self {
+        SessionSnapshot {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            windows,
+        }
+    }
+
+    /// Write the snapshot as RON under `dir` and return the path.
+    pub fn write(&self, dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("session-{}.ron", self.timestamp));
+        let body = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        std::fs::write(&path, body)?;
+        Ok(path)
+    }
+
+    /// Reload a snapshot written by `write`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(ron::de::from_str(&content)?)
+    }
+}
+
+pub fn default_dir() -> PathBuf {
+    PathBuf::from(SESSION_DIR)
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+
+/// How to turn a command line into an actual child process. Compile-time
+/// platform dispatch picks a sane default (same idea as `platform.rs`'s
+/// `CurrentBackend`) - a CNL phrase can still request a specific shell (e.g.
+/// "run build.ps1 using powershell") to override it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Shell {
+    /// Run through a Unix shell (`sh`, `bash`, `zsh`, ...) as `<shell> -c <line>`.
+    Unix(String),
+    /// Run through Windows PowerShell as `powershell -NoProfile -Command <line>`.
+    Powershell,
+    /// Run through the Windows command interpreter as `cmd /C <line>`.
+    Cmd,
+    /// No shell at all: split `line` on whitespace and exec the first token
+    /// directly with the rest as arguments.
+    None,
+}
+
+impl Shell {
+    /// The shell this platform would use absent an explicit CNL override.
+    #[cfg(windows)]
+    pub fn default_for_platform() -> Self {
+        Shell::Powershell
+    }
+
+    #[cfg(not(windows))]
+    pub fn default_for_platform() -> Self {
+        Shell::Unix("sh".to_string())
+    }
+
+    /// Parse a shell name from CNL input (e.g. "powershell", "bash").
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "powershell" | "pwsh" => Some(Shell::Powershell),
+            "cmd" => Some(Shell::Cmd),
+            "none" | "exec" => Some(Shell::None),
+            "sh" | "bash" | "zsh" => Some(Shell::Unix(name.to_lowercase())),
+            _ => None,
+        }
+    }
+
+    /// Resolve `line` into a program and argument list the way this shell
+    /// selection says to. Shared by `command_for` and anything building its
+    /// own process spec (e.g. the supervisor's `WorkerSpec`).
+    pub fn program_and_args(&self, line: &str) -> (String, Vec<String>) {
+        match self {
+            Shell::Unix(shell) => (shell.clone(), vec!["-c".to_string(), line.to_string()]),
+            Shell::Powershell => (
+                "powershell".to_string(),
+                vec!["-NoProfile".to_string(), "-Command".to_string(), line.to_string()],
+            ),
+            Shell::Cmd => ("cmd".to_string(), vec!["/C".to_string(), line.to_string()]),
+            Shell::None => {
+                let mut parts = line.split_whitespace();
+                let program = parts.next().unwrap_or_default().to_string();
+                (program, parts.map(str::to_string).collect())
+            }
+        }
+    }
+
+    /// Build a `tokio::process::Command` that runs `line` the way this shell
+    /// selection says to - a single line for shell modes, or a
+    /// whitespace-split program + args for `Shell::None`.
+    pub fn command_for(&self, line: &str) -> Result<Command> {
+        let (program, args) = self.program_and_args(line);
+        if program.is_empty() {
+            anyhow::bail!("empty command line");
+        }
+        let mut cmd = Command::new(program);
+        cmd.args(args);
+        Ok(cmd)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::safety::SystemState;
+
+/// A declarative description of hypothetical changes - window moves, process
+/// kills, file writes - applied to a cloned `SystemState` by the dry-run path
+/// in `safety::CoreInteractionPrinciple::simulate_execution` instead of ever
+/// touching the real system.
+#[derive(Debug, Clone, Default)]
+pub struct SystemStateDelta {
+    pub window_moves: Vec<WindowMove>,
+    pub process_kills: Vec<u32>,
+    pub file_writes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WindowMove {
+    pub id: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SystemStateDelta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_window(&mut self, id: &str, x: i32, y: i32, width: u32, height: u32) -> &mut Self {
+        self.window_moves.push(WindowMove { id: id.to_string(), x, y, width, height });
+        self
+    }
+
+    pub fn kill_process(&mut self, pid: u32) -> &mut Self {
+        self.process_kills.push(pid);
+        self
+    }
+
+    pub fn write_file(&mut self, path: &str) -> &mut Self {
+        self.file_writes.push(path.to_string());
+        self
+    }
+
+    /// Clone `state` and apply this delta to the clone. Never touches the
+    /// real system - this is the whole point of a dry run.
+    pub fn apply(&self, state: &SystemState) -> SystemState {
+        let mut projected = state.clone();
+
+        for mv in &self.window_moves {
+            if let Some(w) = projected.windows.iter_mut().find(|w| w.id == mv.id) {
+                w.x = mv.x;
+                w.y = mv.y;
+                w.width = mv.width;
+                w.height = mv.height;
+            }
+        }
+
+        if !self.process_kills.is_empty() {
+            projected.processes.retain(|p| !self.process_kills.contains(&p.pid));
+        }
+
+        projected
+    }
+
+    /// File writes have no representable effect on `SystemState`, so they
+    /// surface as plain notes rather than being folded into the simulated
+    /// state like window/process changes are.
+    pub fn declarative_notes(&self) -> Vec<String> {
+        self.file_writes
+            .iter()
+            .map(|path| format!("would write to {} (not applied - dry run)", path))
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+use std::process::{ExitStatus, Stdio};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::mpsc;
+
+/// Which stream a line of output came from.
+#[derive(Debug, Clone)]
+pub enum StreamedLine {
+    Stdout(String),
+    Stderr(String),
+}
+
+/// Turns one line of raw process output into a structured value. Implement
+/// this per tool (cargo/rustc JSON diagnostics, a fleet-coordination
+/// command's own line format, ...) and hand it to `stream_lines` alongside
+/// the running command.
+pub trait ParseFromLine: Clone + Send + Sync + 'static {
+    type Output: Send + 'static;
+
+    /// Return `Some(value)` if `line` matches this parser's format, `None`
+    /// to let it pass through as plain unstructured output.
+    fn parse_line(&self, line: &str) -> Option<Self::Output>;
+}
+
+/// A line of output, alongside whatever a `ParseFromLine` implementation
+/// made of it.
+#[derive(Debug)]
+pub struct StreamedEvent<T> {
+    pub line: StreamedLine,
+    pub parsed: Option<T>,
+}
+
+/// Spawn `cmd` with stdout/stderr piped, so its pid is available to the
+/// caller (e.g. `watch.rs`'s `Restart`/`Signal` handling) before
+/// `stream_lines` starts consuming its output.
+pub fn spawn_piped(cmd: &mut Command) -> Result<Child> {
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+    Ok(cmd.spawn()?)
+}
+
+/// Read `child`'s stdout/stderr line-by-line, emitting each line to `tx` as
+/// soon as it arrives - rather than buffering the whole run like
+/// `Command::output` - and running every line through `parser` so
+/// structured tool output (e.g. `cargo check --message-format=json`) reaches
+/// the caller as data instead of an opaque blob.
+pub async fn stream_lines<P: ParseFromLine>(
+    mut child: Child,
+    parser: P,
+    tx: mpsc::Sender<StreamedEvent<P::Output>>,
+) -> Result<ExitStatus> {
+    let stdout = child.stdout.take().expect("spawn_piped sets stdout");
+    let stderr = child.stderr.take().expect("spawn_piped sets stderr");
+
+    let out_parser = parser.clone();
+    let out_tx = tx.clone();
+    let stdout_task = tokio::spawn(async move {
+        let mut lines = BufReader::new(stdout).lines();
+        while let Ok(Some(text)) = lines.next_line().await {
+            let parsed = out_parser.parse_line(&text);
+            let _ = out_tx.send(StreamedEvent { line: StreamedLine::Stdout(text), parsed }).await;
+        }
+    });
+
+    let err_parser = parser;
+    let err_tx = tx;
+    let stderr_task = tokio::spawn(async move {
+        let mut lines = BufReader::new(stderr).lines();
+        while let Ok(Some(text)) = lines.next_line().await {
+            let parsed = err_parser.parse_line(&text);
+            let _ = err_tx.send(StreamedEvent { line: StreamedLine::Stderr(text), parsed }).await;
+        }
+    });
+
+    let status = child.wait().await?;
+    let _ = tokio::join!(stdout_task, stderr_task);
+    Ok(status)
+}
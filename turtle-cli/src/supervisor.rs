@@ -0,0 +1,337 @@
+use anyhow::Result;
+use nix::sys::signal::{self, Signal};
+use nix::unistd::Pid;
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::process::{Child, Command};
+use tokio::sync::mpsc;
+
+/// Identifies a supervised worker. A plain alias rather than a newtype since
+/// names are still how callers (CNL commands, `fleet status`) address them.
+pub type DaemonId = String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    Never,
+    OnFailure,
+    Always,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkerSpec {
+    pub name: String,
+    pub command: String,
+    pub args: Vec<String>,
+    pub restart_policy: RestartPolicy,
+    pub stop_signal: Signal,
+    pub stop_timeout: Duration,
+    /// Crash-loop guard: give up (transition to `Failed`) after this many
+    /// restarts within `restart_window`.
+    pub max_restarts: u32,
+    pub restart_window: Duration,
+    /// Exponential backoff between a crash and the next relaunch, starting
+    /// at `initial_backoff` and doubling up to `max_backoff` each time.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl WorkerSpec {
+    pub fn new(name: impl Into<String>, command: impl Into<String>) -> Self {
+        WorkerSpec {
+            name: name.into(),
+            command: command.into(),
+            args: Vec::new(),
+            restart_policy: RestartPolicy::Never,
+            stop_signal: Signal::SIGTERM,
+            stop_timeout: Duration::from_secs(10),
+            max_restarts: 5,
+            restart_window: Duration::from_secs(60),
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+/// How a supervised child ended, surfaced back to callers (e.g. the REPL's
+/// `fleet status`) instead of a one-shot `pgrep` count.
+#[derive(Debug, Clone)]
+pub enum ProcessEnd {
+    ExitedOk(String),
+    ExitedErr(String, i32),
+    KilledBySignal(String, i32),
+    TimedOut(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkerState {
+    Starting,
+    Running(u32), // pid
+    Exited,
+    Failed,
+    Restarting,
+    Stopped,
+}
+
+struct Worker {
+    spec: WorkerSpec,
+    child: Option<Child>,
+    state: WorkerState,
+    restart_count: u32,
+    backoff: Duration,
+    started_at: Instant,
+}
+
+/// The result of a backed-off relaunch, reported back from the detached
+/// task `reap()` spawns for it (see `RestartOutcome`/`restart_rx`).
+struct RestartOutcome {
+    name: String,
+    result: Result<Child>,
+}
+
+/// Spawns, tracks, and stops turtle worker processes, each in its own
+/// process group so a stop signal reaches the whole child tree.
+pub struct Supervisor {
+    workers: HashMap<String, Worker>,
+    end_tx: mpsc::Sender<ProcessEnd>,
+    end_rx: mpsc::Receiver<ProcessEnd>,
+    restart_tx: mpsc::Sender<RestartOutcome>,
+    restart_rx: mpsc::Receiver<RestartOutcome>,
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        let (end_tx, end_rx) = mpsc::channel(64);
+        let (restart_tx, restart_rx) = mpsc::channel(64);
+        Supervisor {
+            workers: HashMap::new(),
+            end_tx,
+            end_rx,
+            restart_tx,
+            restart_rx,
+        }
+    }
+
+    pub fn spawn(&mut self, spec: WorkerSpec) -> Result<()> {
+        let name = spec.name.clone();
+        let backoff = spec.initial_backoff;
+        self.workers.insert(
+            name.clone(),
+            Worker {
+                spec: spec.clone(),
+                child: None,
+                state: WorkerState::Starting,
+                restart_count: 0,
+                backoff,
+                started_at: Instant::now(),
+            },
+        );
+
+        match Self::launch(&spec) {
+            Ok(child) => {
+                let pid = child.id().unwrap_or(0);
+                let worker = self.workers.get_mut(&name).unwrap();
+                worker.child = Some(child);
+                worker.state = WorkerState::Running(pid);
+                Ok(())
+            }
+            Err(e) => {
+                if let Some(worker) = self.workers.get_mut(&name) {
+                    worker.state = WorkerState::Failed;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    fn launch(spec: &WorkerSpec) -> Result<Child> {
+        let mut cmd = Command::new(&spec.command);
+        cmd.args(&spec.args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .process_group(0); // new process group so signals reach the whole tree
+        Ok(cmd.spawn()?)
+    }
+
+    /// Send `signal` (or the worker's configured stop-signal) to the whole
+    /// process group, wait up to `timeout` (or the worker's configured
+    /// stop-timeout) for a clean exit, and escalate to SIGKILL if it's still
+    /// alive once that elapses. Returns how the process actually ended so
+    /// callers can tell a clean exit from a forced kill, instead of
+    /// pattern-matching a command line - this only ever signals a pid this
+    /// supervisor itself spawned.
+    pub async fn stop(&mut self, name: &str, signal: Option<Signal>, timeout: Option<Duration>) -> Result<ProcessEnd> {
+        let Some(worker) = self.workers.get_mut(name) else {
+            anyhow::bail!("no such worker: {}", name);
+        };
+        let stop_signal = signal.unwrap_or(worker.spec.stop_signal);
+        let stop_timeout = timeout.unwrap_or(worker.spec.stop_timeout);
+
+        let Some(mut child) = worker.child.take() else {
+            worker.state = WorkerState::Stopped;
+            return Ok(ProcessEnd::ExitedOk(name.to_string()));
+        };
+        let Some(pid) = child.id() else {
+            worker.state = WorkerState::Stopped;
+            return Ok(ProcessEnd::ExitedOk(name.to_string()));
+        };
+
+        println!("🛑 Sending {:?} to {} (pid {})", stop_signal, name, pid);
+        let _ = signal::killpg(Pid::from_raw(pid as i32), stop_signal);
+
+        let outcome = match tokio::time::timeout(stop_timeout, child.wait()).await {
+            Ok(Ok(status)) if status.success() => ProcessEnd::ExitedOk(name.to_string()),
+            Ok(Ok(status)) => ProcessEnd::ExitedErr(name.to_string(), status.code().unwrap_or(-1)),
+            Ok(Err(_)) => ProcessEnd::ExitedErr(name.to_string(), -1),
+            Err(_) => {
+                println!("⏱️ {} did not stop within {:?}, escalating to SIGKILL", name, stop_timeout);
+                let _ = signal::killpg(Pid::from_raw(pid as i32), Signal::SIGKILL);
+                let _ = child.wait().await;
+                ProcessEnd::TimedOut(name.to_string())
+            }
+        };
+
+        worker.state = WorkerState::Stopped;
+        let _ = self.end_tx.send(outcome.clone()).await;
+        Ok(outcome)
+    }
+
+    /// Poll for workers that have exited on their own and apply restart
+    /// policy, backing off exponentially between relaunches and giving up
+    /// (transitioning to `Failed`) once a worker crash-loops past
+    /// `max_restarts` within `restart_window`.
+    pub async fn reap(&mut self) -> Result<Vec<ProcessEnd>> {
+        let mut ended = Vec::new();
+        let names: Vec<String> = self.workers.keys().cloned().collect();
+
+        for name in names {
+            let exited = {
+                let worker = self.workers.get_mut(&name).unwrap();
+                match worker.child.as_mut() {
+                    Some(child) => child.try_wait()?,
+                    None => None,
+                }
+            };
+
+            let Some(status) = exited else { continue };
+
+            let worker = self.workers.get_mut(&name).unwrap();
+            worker.child = None;
+
+            let outcome = if status.success() {
+                ProcessEnd::ExitedOk(name.clone())
+            } else {
+                ProcessEnd::ExitedErr(name.clone(), status.code().unwrap_or(-1))
+            };
+            ended.push(outcome.clone());
+
+            let should_restart = match worker.spec.restart_policy {
+                RestartPolicy::Always => true,
+                RestartPolicy::OnFailure => !status.success(),
+                RestartPolicy::Never => false,
+            };
+
+            if !should_restart {
+                worker.state = if status.success() { WorkerState::Exited } else { WorkerState::Failed };
+                continue;
+            }
+
+            // A run that outlived the restart window is healthy - forgive
+            // past crashes and reset the backoff before trying again.
+            if worker.started_at.elapsed() > worker.spec.restart_window {
+                worker.restart_count = 0;
+                worker.backoff = worker.spec.initial_backoff;
+            }
+
+            if worker.restart_count >= worker.spec.max_restarts {
+                println!(
+                    "🛑 {} crash-looped {} time(s) within {:?}, giving up",
+                    name, worker.restart_count, worker.spec.restart_window
+                );
+                worker.state = WorkerState::Failed;
+                continue;
+            }
+
+            worker.restart_count += 1;
+            worker.state = WorkerState::Restarting;
+            let backoff = worker.backoff;
+            worker.backoff = (worker.backoff * 2).min(worker.spec.max_backoff);
+            let spec = worker.spec.clone();
+            println!(
+                "🔁 Restarting {} in {:?} (attempt {}/{}, policy: {:?})",
+                name, backoff, worker.restart_count, spec.max_restarts, spec.restart_policy
+            );
+
+            // `reap` runs with the caller's lock on the whole Supervisor held
+            // (see `SupervisorReapWorker::work`, polled every 5s) - sleeping
+            // out the backoff right here would freeze every other
+            // Supervisor-facing command for up to `max_backoff`. Do the
+            // wait-then-relaunch on a detached task instead, and pick up its
+            // result on a later `reap()` call via `restart_rx`, the same way
+            // `stop()`'s outcome is picked up via `end_rx`.
+            let restart_tx = self.restart_tx.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(backoff).await;
+                let result = Self::launch(&spec);
+                let _ = restart_tx.send(RestartOutcome { name, result }).await;
+            });
+        }
+
+        // Pick up any relaunches that finished their backoff since the last poll.
+        while let Ok(RestartOutcome { name, result }) = self.restart_rx.try_recv() {
+            let Some(worker) = self.workers.get_mut(&name) else { continue };
+            match result {
+                Ok(child) => {
+                    let pid = child.id().unwrap_or(0);
+                    worker.child = Some(child);
+                    worker.state = WorkerState::Running(pid);
+                    worker.started_at = Instant::now();
+                }
+                Err(e) => {
+                    println!("❌ Failed to restart {}: {}", name, e);
+                    worker.state = WorkerState::Failed;
+                }
+            }
+        }
+
+        // Drain anything queued by an explicit stop() call too.
+        while let Ok(end) = self.end_rx.try_recv() {
+            ended.push(end);
+        }
+
+        Ok(ended)
+    }
+
+    /// Stop a supervised worker and stop tracking it entirely, as opposed to
+    /// `stop()` which just transitions it to `Stopped` and keeps it around.
+    pub async fn unsupervise(&mut self, name: &DaemonId) -> Result<()> {
+        self.stop(name, None, None).await?;
+        self.workers.remove(name);
+        Ok(())
+    }
+
+    /// Parse a signal name like "SIGINT" (case-insensitive, "SIG" prefix
+    /// optional) out of a CNL phrase such as "stop nginx with SIGINT".
+    pub fn parse_signal_name(name: &str) -> Option<Signal> {
+        let upper = name.trim().to_uppercase();
+        let stripped = upper.strip_prefix("SIG").unwrap_or(&upper);
+        match stripped {
+            "TERM" => Some(Signal::SIGTERM),
+            "KILL" => Some(Signal::SIGKILL),
+            "INT" => Some(Signal::SIGINT),
+            "HUP" => Some(Signal::SIGHUP),
+            "QUIT" => Some(Signal::SIGQUIT),
+            "USR1" => Some(Signal::SIGUSR1),
+            "USR2" => Some(Signal::SIGUSR2),
+            _ => None,
+        }
+    }
+
+    /// Each supervised worker's current state and restart count so far.
+    pub fn status(&self) -> Vec<(DaemonId, WorkerState, u32)> {
+        self.workers
+            .iter()
+            .map(|(name, w)| (name.clone(), w.state.clone(), w.restart_count))
+            .collect()
+    }
+}
@@ -1,4 +1,6 @@
 use anyhow::Result;
+use crate::containers;
+use crate::db::DbCtx;
 use crate::types::TurtleState;
 
 pub async fn get_turtle_state() -> Result<TurtleState> {
@@ -6,9 +8,34 @@ pub async fn get_turtle_state() -> Result<TurtleState> {
     Ok(TurtleState::PreBoot)
 }
 
+/// Bring every declared DC endpoint's service containers up and report the
+/// result observed from the container runtime, rather than a printed guess.
 pub async fn transition_to_standard() -> Result<()> {
     println!("🔄 Transitioning to standard turtle mode...");
-    println!("✅ Standard mode active");
+
+    let db = DbCtx::open()?;
+    let mut all_deployed = true;
+
+    for endpoint in containers::default_endpoints() {
+        match containers::deploy(&endpoint).await {
+            Ok(_) => {
+                let pct = containers::refresh_deploy_status(&endpoint, &db).await.unwrap_or(0);
+                if pct < 100 {
+                    all_deployed = false;
+                }
+            }
+            Err(e) => {
+                println!("⚠️ {} deploy failed: {}", endpoint.dc, e);
+                all_deployed = false;
+            }
+        }
+    }
+
+    if all_deployed {
+        println!("✅ Standard mode active - all DCs reporting fully deployed");
+    } else {
+        println!("⚠️ Standard mode partially active - see `turtle infra` for per-DC status");
+    }
     Ok(())
 }
 
@@ -16,4 +43,4 @@ pub async fn transition_to_secure_enclave() -> Result<()> {
     println!("🔒 Transitioning to secure enclave mode...");
     println!("🛡️ Secure enclave active");
     Ok(())
-}
\ No newline at end of file
+}
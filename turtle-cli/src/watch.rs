@@ -0,0 +1,245 @@
+use anyhow::Result;
+use nix::sys::signal::{self, Signal};
+use nix::unistd::Pid;
+use notify::{RecursiveMode, Watcher};
+use std::path::PathBuf;
+use std::sync::mpsc as std_mpsc;
+use std::time::Duration;
+use tokio::process::Command;
+
+use crate::dashboard::Dashboard;
+
+/// What to do when a new filesystem event arrives while the previous
+/// action is still running. Mirrors watchexec's on-busy-update modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnBusyUpdate {
+    /// Let the current run finish, then start a fresh one with the latest events.
+    Queue,
+    /// Drop the event entirely; the in-flight run keeps going untouched.
+    DoNothing,
+    /// Kill the in-flight run and relaunch immediately.
+    Restart,
+    /// Forward a signal to the in-flight run instead of killing it outright.
+    Signal(i32),
+}
+
+impl Default for OnBusyUpdate {
+    fn default() -> Self {
+        OnBusyUpdate::Queue
+    }
+}
+
+/// The turtle action to re-run whenever the watched paths change.
+#[derive(Debug, Clone)]
+pub enum WatchAction {
+    DashboardRefresh,
+    InfraStatus,
+    Command(String),
+}
+
+pub struct WatchConfig {
+    pub paths: Vec<PathBuf>,
+    pub action: WatchAction,
+    pub on_busy_update: OnBusyUpdate,
+    pub debounce: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        WatchConfig {
+            paths: vec![
+                PathBuf::from("/home/tupshin/turtle"),
+                PathBuf::from("/home/tupshin/turtle-rust-private"),
+                PathBuf::from("/home/tupshin/turtle-ts-private"),
+                PathBuf::from("/home/tupshin/.turtle"),
+            ],
+            action: WatchAction::DashboardRefresh,
+            on_busy_update: OnBusyUpdate::Queue,
+            debounce: Duration::from_millis(300),
+        }
+    }
+}
+
+/// A still-running action. `Command` actions own the child's pid so
+/// `Restart`/`Signal` can act on the real process instead of just abandoning
+/// the task awaiting it.
+enum RunningJob {
+    Task(tokio::task::JoinHandle<Result<()>>),
+    Process { pid: u32, handle: tokio::task::JoinHandle<Result<()>> },
+}
+
+impl RunningJob {
+    fn is_finished(&self) -> bool {
+        match self {
+            RunningJob::Task(h) => h.is_finished(),
+            RunningJob::Process { handle, .. } => handle.is_finished(),
+        }
+    }
+
+    /// Kill the in-flight run outright (process group, then the local task).
+    fn kill(self) {
+        match self {
+            RunningJob::Task(handle) => handle.abort(),
+            RunningJob::Process { pid, handle } => {
+                let _ = signal::killpg(Pid::from_raw(pid as i32), Signal::SIGTERM);
+                handle.abort();
+            }
+        }
+    }
+
+    /// Forward a signal to the in-flight run without ending it.
+    fn signal(&self, sig: i32) {
+        match self {
+            RunningJob::Task(_) => {
+                println!("📡 This action has no process to signal; ignoring");
+            }
+            RunningJob::Process { pid, .. } => {
+                if let Ok(signal) = Signal::try_from(sig) {
+                    let _ = signal::killpg(Pid::from_raw(*pid as i32), signal);
+                } else {
+                    println!("⚠️ Unknown signal number: {}", sig);
+                }
+            }
+        }
+    }
+}
+
+pub async fn run(config: WatchConfig) -> Result<()> {
+    println!("👁️ Watching {} path(s) for changes...", config.paths.len());
+    for path in &config.paths {
+        println!("   - {}", path.display());
+    }
+
+    let (raw_tx, raw_rx) = std_mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event {
+            let _ = raw_tx.send(event);
+        }
+    })?;
+
+    for path in &config.paths {
+        if path.exists() {
+            watcher.watch(path, RecursiveMode::Recursive)?;
+        } else {
+            println!("⚠️ Skipping missing watch path: {}", path.display());
+        }
+    }
+
+    // Run once immediately so the dashboard is live before the first event.
+    spawn_action(&config.action).await?;
+
+    let mut running: Option<RunningJob> = None;
+    let mut pending = false;
+
+    loop {
+        // Coalesce a burst of events within the debounce window into one run.
+        match raw_rx.recv_timeout(config.debounce) {
+            Ok(_first) => {
+                tokio::time::sleep(config.debounce).await;
+                while raw_rx.try_recv().is_ok() {}
+            }
+            Err(std_mpsc::RecvTimeoutError::Timeout) => {
+                // No new event, but a run that finished while `Queue`d one
+                // still owes us a re-run - fire it as soon as the job frees up.
+                if pending && running.as_ref().map(|job| job.is_finished()).unwrap_or(true) {
+                    pending = false;
+                    running = Some(spawn_action(&config.action).await?);
+                }
+                continue;
+            }
+            Err(std_mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+
+        let busy = running.as_ref().map(|job| !job.is_finished()).unwrap_or(false);
+
+        if busy {
+            match config.on_busy_update {
+                OnBusyUpdate::DoNothing => {
+                    println!("⏳ Previous run still in progress, ignoring event");
+                    continue;
+                }
+                OnBusyUpdate::Queue => {
+                    println!("📥 Previous run still in progress, queuing this event");
+                    pending = true;
+                    continue;
+                }
+                OnBusyUpdate::Restart => {
+                    println!("🔁 Restarting in-flight run");
+                    if let Some(job) = running.take() {
+                        job.kill();
+                    }
+                }
+                OnBusyUpdate::Signal(sig) => {
+                    println!("📡 Forwarding signal {} to in-flight run (continuing to watch)", sig);
+                    if let Some(job) = &running {
+                        job.signal(sig);
+                    }
+                    continue;
+                }
+            }
+        }
+
+        pending = false;
+        running = Some(spawn_action(&config.action).await?);
+    }
+
+    Ok(())
+}
+
+/// Launch `action` and return a handle to its in-flight run. `Command`
+/// actions are spawned (not awaited to completion) so their pid is captured
+/// for `Restart`/`Signal`, then waited on in a background task.
+async fn spawn_action(action: &WatchAction) -> Result<RunningJob> {
+    match action {
+        WatchAction::DashboardRefresh => {
+            let handle = tokio::spawn(async move {
+                let mut dashboard = Dashboard::new().await?;
+                dashboard.show_compact().await
+            });
+            Ok(RunningJob::Task(handle))
+        }
+        WatchAction::InfraStatus => {
+            let handle = tokio::spawn(async move {
+                let mut dashboard = Dashboard::new().await?;
+                dashboard.show_infrastructure_focused().await
+            });
+            Ok(RunningJob::Task(handle))
+        }
+        WatchAction::Command(cmd) => {
+            println!("▶️ Running: {}", cmd);
+            let mut command = Command::new("sh");
+            command.args(&["-c", cmd]).process_group(0); // new process group so Restart/Signal reach the whole tree
+            let child = crate::streaming::spawn_piped(&mut command)?;
+            let pid = child.id().unwrap_or(0);
+
+            let (tx, mut rx) = tokio::sync::mpsc::channel::<crate::streaming::StreamedEvent<crate::diagnostics::Diagnostic>>(256);
+            let printer = tokio::spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    match event.parsed {
+                        Some(diag) => println!("🩺 [{:?}] {}", diag.level, diag.message),
+                        None => match event.line {
+                            crate::streaming::StreamedLine::Stdout(line) => println!("{}", line),
+                            crate::streaming::StreamedLine::Stderr(line) => eprintln!("{}", line),
+                        },
+                    }
+                }
+            });
+
+            let cmd_label = cmd.clone();
+            let handle = tokio::spawn(async move {
+                let status = crate::streaming::stream_lines(
+                    child,
+                    crate::diagnostics::CargoJsonDiagnosticParser,
+                    tx,
+                )
+                .await?;
+                let _ = printer.await;
+                if !status.success() {
+                    println!("❌ Command exited with: {} ({})", status, cmd_label);
+                }
+                Ok(())
+            });
+            Ok(RunningJob::Process { pid, handle })
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use anyhow::Result;
 
-pub async fn focus_mode(_duration: Option<String>) -> Result<()> {
+pub async fn focus_mode(duration: Option<String>) -> Result<()> {
     println!("🎯 TURTLE FOCUS MODE");
-    println!("Deep work session initiated");
+    match duration {
+        Some(spec) => {
+            let duration = crate::scheduler::parse_duration(&spec)?;
+            println!("Deep work session initiated for {}", crate::scheduler::format_duration(duration));
+        }
+        None => println!("Deep work session initiated"),
+    }
     Ok(())
 }
 
@@ -0,0 +1,301 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// What a `Worker` did on its latest tick. `Idle(d)` is the common case for
+/// the interval-driven workers below - there's nothing to do again for at
+/// least `d`, so `WorkerManager`'s loop can sleep instead of busy-polling.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerState {
+    Busy,
+    Idle(Duration),
+    Done,
+}
+
+/// A long-lived background task, driven to completion one `work()` call at
+/// a time by `WorkerManager` rather than owning its own loop - that's what
+/// lets the manager throttle, pause, and cancel it uniformly regardless of
+/// what the worker actually does.
+#[async_trait]
+pub trait Worker: Send {
+    fn name(&self) -> &str;
+
+    /// One-line description of what this worker does and how often,
+    /// surfaced by the REPL's `workers` command.
+    fn status(&self) -> String;
+
+    async fn work(&mut self) -> WorkerState;
+}
+
+/// Sent over a worker's control channel to steer its `WorkerManager` loop.
+pub enum ControlMsg {
+    Start,
+    Pause,
+    Cancel,
+}
+
+/// What `WorkerManager::status` reports for a worker - `Active` while
+/// `work()` is running or about to run, `Idle` while sleeping off a
+/// `WorkerState::Idle` tick, `Paused` after a `Pause` message, and `Dead`
+/// once the loop has exited, whether from `Cancel`, `WorkerState::Done`, or
+/// a panic inside `work()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunState {
+    Active,
+    Idle,
+    Paused,
+    Dead,
+}
+
+struct WorkerHandle {
+    name: String,
+    status: String,
+    run_state: Arc<Mutex<RunState>>,
+    control_tx: mpsc::Sender<ControlMsg>,
+    task: JoinHandle<()>,
+}
+
+/// Owns every spawned worker's handle. Each worker runs in its own task, so
+/// one panicking or blocking never stalls the others or the REPL itself.
+#[derive(Default)]
+pub struct WorkerManager {
+    handles: Vec<WorkerHandle>,
+}
+
+impl WorkerManager {
+    pub fn new() -> Self {
+        WorkerManager { handles: Vec::new() }
+    }
+
+    /// Spawn `worker` into its own task. After every `WorkerState::Idle(d)`
+    /// tick, the loop sleeps `throttle + d` before calling `work()` again -
+    /// `throttle` is a floor so a worker reporting a very short `d` still
+    /// can't spin the CPU.
+    pub fn spawn(&mut self, worker: Box<dyn Worker>, throttle: Duration) {
+        let name = worker.name().to_string();
+        let status = worker.status();
+        let run_state = Arc::new(Mutex::new(RunState::Active));
+        let (control_tx, control_rx) = mpsc::channel(8);
+
+        let task = tokio::spawn(Self::run_loop(worker, throttle, run_state.clone(), control_rx));
+
+        self.handles.push(WorkerHandle { name, status, run_state, control_tx, task });
+    }
+
+    async fn run_loop(
+        mut worker: Box<dyn Worker>,
+        throttle: Duration,
+        run_state: Arc<Mutex<RunState>>,
+        mut control_rx: mpsc::Receiver<ControlMsg>,
+    ) {
+        let mut paused = false;
+
+        loop {
+            while let Ok(msg) = control_rx.try_recv() {
+                match msg {
+                    ControlMsg::Pause => paused = true,
+                    ControlMsg::Start => paused = false,
+                    ControlMsg::Cancel => {
+                        *run_state.lock().unwrap() = RunState::Dead;
+                        return;
+                    }
+                }
+            }
+
+            if paused {
+                *run_state.lock().unwrap() = RunState::Paused;
+                match control_rx.recv().await {
+                    Some(ControlMsg::Start) => {
+                        paused = false;
+                        continue;
+                    }
+                    Some(ControlMsg::Cancel) | None => {
+                        *run_state.lock().unwrap() = RunState::Dead;
+                        return;
+                    }
+                    Some(ControlMsg::Pause) => continue,
+                }
+            }
+
+            *run_state.lock().unwrap() = RunState::Active;
+            match worker.work().await {
+                WorkerState::Busy => {}
+                WorkerState::Idle(d) => {
+                    *run_state.lock().unwrap() = RunState::Idle;
+                    tokio::time::sleep(throttle + d).await;
+                }
+                WorkerState::Done => {
+                    *run_state.lock().unwrap() = RunState::Dead;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Each worker's name, one-line status description, and live run state -
+    /// a task that's panicked or returned `Done` reports `Dead` here even
+    /// though nothing ever set its `run_state` to that explicitly.
+    pub fn status(&self) -> Vec<(String, String, RunState)> {
+        self.handles
+            .iter()
+            .map(|h| {
+                let run_state = if h.task.is_finished() {
+                    RunState::Dead
+                } else {
+                    *h.run_state.lock().unwrap()
+                };
+                (h.name.clone(), h.status.clone(), run_state)
+            })
+            .collect()
+    }
+
+    /// Send a `Start`/`Pause`/`Cancel` message to the named worker, for the
+    /// REPL's `worker <name> pause|resume|cancel` command.
+    pub async fn control(&self, name: &str, msg: ControlMsg) -> Result<()> {
+        let handle = self
+            .handles
+            .iter()
+            .find(|h| h.name == name)
+            .ok_or_else(|| anyhow::anyhow!("no such worker: {}", name))?;
+        handle
+            .control_tx
+            .send(msg)
+            .await
+            .map_err(|_| anyhow::anyhow!("worker {} is no longer listening", name))
+    }
+}
+
+/// Pings the mesh's self-healing pass on `mesh_healing_interval`, per
+/// `MeshResilienceConfig`. The actual healing logic (`automatic_recovery`,
+/// `connection_retry_count`) isn't implemented yet - this is the timer that
+/// would drive it, replacing an interval that previously existed only as
+/// config with no task reading it.
+pub struct MeshHealingWorker {
+    interval: Duration,
+    automatic_recovery: bool,
+}
+
+impl MeshHealingWorker {
+    pub fn new(interval: Duration, automatic_recovery: bool) -> Self {
+        MeshHealingWorker { interval, automatic_recovery }
+    }
+}
+
+#[async_trait]
+impl Worker for MeshHealingWorker {
+    fn name(&self) -> &str {
+        "mesh-healing"
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "heals mesh partitions every {:?} (automatic recovery: {})",
+            self.interval, self.automatic_recovery
+        )
+    }
+
+    async fn work(&mut self) -> WorkerState {
+        if self.automatic_recovery {
+            println!("🩹 Mesh healing: checking for partitioned turtles");
+        }
+        WorkerState::Idle(self.interval)
+    }
+}
+
+/// Re-enumerates known fleet endpoints on `discovery_interval`, per
+/// `FleetCommunicationConfig`.
+pub struct FleetDiscoveryWorker {
+    interval: Duration,
+}
+
+impl FleetDiscoveryWorker {
+    pub fn new(interval: Duration) -> Self {
+        FleetDiscoveryWorker { interval }
+    }
+}
+
+#[async_trait]
+impl Worker for FleetDiscoveryWorker {
+    fn name(&self) -> &str {
+        "fleet-discovery"
+    }
+
+    fn status(&self) -> String {
+        format!("rediscovers fleet endpoints every {:?}", self.interval)
+    }
+
+    async fn work(&mut self) -> WorkerState {
+        let endpoints = crate::fleet::endpoints();
+        println!("🔍 Fleet discovery: {} known data center(s)", endpoints.len());
+        WorkerState::Idle(self.interval)
+    }
+}
+
+/// Refreshes each data center's observed deploy status on
+/// `health_check_interval`, per `FleetCommunicationConfig`.
+pub struct HealthCheckWorker {
+    interval: Duration,
+}
+
+impl HealthCheckWorker {
+    pub fn new(interval: Duration) -> Self {
+        HealthCheckWorker { interval }
+    }
+}
+
+#[async_trait]
+impl Worker for HealthCheckWorker {
+    fn name(&self) -> &str {
+        "health-check"
+    }
+
+    fn status(&self) -> String {
+        format!("refreshes DC deploy status every {:?}", self.interval)
+    }
+
+    async fn work(&mut self) -> WorkerState {
+        let Ok(db) = crate::db::DbCtx::open() else {
+            return WorkerState::Idle(self.interval);
+        };
+        for endpoint in crate::containers::default_endpoints() {
+            let _ = crate::containers::refresh_deploy_status(&endpoint, &db).await;
+        }
+        WorkerState::Idle(self.interval)
+    }
+}
+
+/// Polls `Supervisor::reap` so restart policy self-heals on its own -
+/// previously `reap()` only ran when a user happened to type "fleet status",
+/// which left an `Always`/`OnFailure` worker dead indefinitely between
+/// queries.
+pub struct SupervisorReapWorker {
+    supervisor: Arc<tokio::sync::Mutex<crate::supervisor::Supervisor>>,
+    interval: Duration,
+}
+
+impl SupervisorReapWorker {
+    pub fn new(supervisor: Arc<tokio::sync::Mutex<crate::supervisor::Supervisor>>, interval: Duration) -> Self {
+        SupervisorReapWorker { supervisor, interval }
+    }
+}
+
+#[async_trait]
+impl Worker for SupervisorReapWorker {
+    fn name(&self) -> &str {
+        "supervisor-reap"
+    }
+
+    fn status(&self) -> String {
+        format!("reaps and restarts crashed supervised workers every {:?}", self.interval)
+    }
+
+    async fn work(&mut self) -> WorkerState {
+        let mut supervisor = self.supervisor.lock().await;
+        let _ = supervisor.reap().await;
+        WorkerState::Idle(self.interval)
+    }
+}